@@ -0,0 +1,88 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::Path, path::PathBuf};
+
+/// `NN` of every `<dir>/NN.rs`, sorted.
+fn days_in(dir: &Path) -> Vec<String> {
+    let mut days: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".rs")?;
+            if stem.len() == 2 && stem.chars().all(|c| c.is_ascii_digit()) {
+                Some(stem.to_owned())
+            } else {
+                None
+            }
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+/// `src/<year>/bin` of every year besides the default one, sorted.
+fn year_bin_dirs(src: &Path) -> Vec<(String, PathBuf)> {
+    let mut years: Vec<(String, PathBuf)> = fs::read_dir(src)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            if name.len() == 4 && name.chars().all(|c| c.is_ascii_digit()) {
+                Some((name.clone(), src.join(name).join("bin")))
+            } else {
+                None
+            }
+        })
+        .collect();
+    years.sort();
+    years
+}
+
+/// Collects every `src/bin/NN.rs` and `src/<year>/bin/NN.rs` so the library can compile the day solutions into its registry.
+fn main() {
+    // new years are registered as bins in `Cargo.toml` by `cargo scaffold --year`.
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src = Path::new(&root).join("src");
+
+    let mut modules: Vec<(String, PathBuf)> = days_in(&src.join("bin"))
+        .into_iter()
+        .map(|day| {
+            (
+                format!("day{}", day),
+                src.join("bin").join(format!("{}.rs", day)),
+            )
+        })
+        .collect();
+
+    for (year, bin_dir) in year_bin_dirs(&src) {
+        println!("cargo:rerun-if-changed=src/{}/bin", year);
+        for day in days_in(&bin_dir) {
+            let path = bin_dir.join(format!("{}.rs", day));
+            modules.push((format!("y{}_day{}", year, day), path));
+        }
+    }
+
+    let mut registry = String::new();
+
+    for (module, path) in modules.iter() {
+        registry.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {:?}]\nmod {};\n",
+            path.to_string_lossy(),
+            module
+        ));
+    }
+
+    registry.push_str("\nstatic SOLUTIONS: &[&dyn crate::Solution] = &[\n");
+    for (module, _) in modules.iter() {
+        registry.push_str(&format!("    &{}::Solver,\n", module));
+    }
+    registry.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, registry).unwrap();
+}
@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Enable with `--features memory`, e.g. `cargo solve 14 --features memory`.
+ */
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Wraps the system allocator, counting allocations and the bytes in use per thread.
+pub struct CountingAllocator;
+
+// per thread, so parts measured at the same time on other threads (`cargo all --jobs N`)
+// neither reset nor inflate each other's numbers. `const` thread locals never allocate.
+thread_local! {
+    /// Signed: memory freed here may have been allocated by another thread.
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// `try_with` as the allocator still runs while a thread's locals are being torn down.
+fn grow(size: usize) {
+    let _ = CURRENT.try_with(|current| {
+        current.set(current.get() + size as isize);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+    });
+}
+
+fn shrink(size: usize) {
+    let _ = CURRENT.try_with(|current| current.set(current.get() - size as isize));
+}
+
+fn count_allocation() {
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count_allocation();
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count_allocation();
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Memory used by one run of a part.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryStats {
+    /// Most bytes allocated at once on top of what was in use before the run.
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+/// Whether the counting allocator is installed (the `memory` feature).
+pub const fn enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+/// Counts from [`Measurement::start`] until [`Measurement::finish`], on the calling thread only.
+pub struct Measurement {
+    baseline: isize,
+}
+
+impl Measurement {
+    pub fn start() -> Self {
+        let baseline = CURRENT.with(Cell::get);
+        PEAK.with(|peak| peak.set(baseline));
+        ALLOCATIONS.with(|allocations| allocations.set(0));
+        Measurement { baseline }
+    }
+
+    /// `None` without the `memory` feature, as nothing was counted.
+    pub fn finish(self) -> Option<MemoryStats> {
+        if !enabled() {
+            return None;
+        }
+        Some(MemoryStats {
+            peak_bytes: (PEAK.with(Cell::get) - self.baseline).max(0) as usize,
+            allocations: ALLOCATIONS.with(Cell::get),
+        })
+    }
+}
+
+/// `1536` -> `1.50 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_measurement_per_thread() {
+        let measurement = Measurement::start();
+        grow(100);
+        // another thread's allocations, e.g. a day running in parallel, are not counted here.
+        std::thread::spawn(|| {
+            let other = Measurement::start();
+            grow(5000);
+            shrink(5000);
+            other.finish();
+        })
+        .join()
+        .unwrap();
+        shrink(100);
+
+        // spawning the thread allocates here too when the allocator is installed.
+        let peak = PEAK.with(Cell::get) - measurement.baseline;
+        assert!((100..5000).contains(&peak), "peak {}", peak);
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_root;
+use crate::layout::DEFAULT_YEAR;
+use std::collections::HashMap;
+use std::fs;
+use std::process;
+use std::sync::OnceLock;
+use toml::Value;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// How an answer compares to the one recorded in `answers.toml`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Verified answers, keyed by year, day and part.
+/// Days of [`DEFAULT_YEAR`] are top-level tables, other years nest them:
+///
+/// ```toml
+/// [01]
+/// part_one = 24000
+/// part_two = "MCD"
+///
+/// [2023.01]
+/// part_one = 142
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let value: Value = content.parse().map_err(|e| format!("{}", e))?;
+        let mut answers = HashMap::new();
+
+        for (key, table) in value.as_table().into_iter().flatten() {
+            let table = table
+                .as_table()
+                .ok_or_else(|| format!("{} must be a table", key))?;
+
+            if key.len() == 4 {
+                let year = key
+                    .parse::<u16>()
+                    .map_err(|_| format!("\"{}\" is not a year", key))?;
+                for (day_key, parts) in table {
+                    let parts = parts
+                        .as_table()
+                        .ok_or_else(|| format!("{}.{} must be a table", key, day_key))?;
+                    parse_day(&mut answers, year, day_key, parts)?;
+                }
+            } else {
+                parse_day(&mut answers, DEFAULT_YEAR, key, table)?;
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|x| x.as_str())
+    }
+
+    pub fn verdict(&self, year: u16, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if Some(expected) == answer => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+        }
+    }
+}
+
+fn parse_day(
+    answers: &mut HashMap<(u16, u8, u8), String>,
+    year: u16,
+    day_key: &str,
+    parts: &toml::value::Table,
+) -> Result<(), String> {
+    let day = day_key
+        .parse::<u8>()
+        .map_err(|_| format!("\"{}\" is not a day", day_key))?;
+
+    for (part_key, answer) in parts {
+        let part = match part_key.as_str() {
+            "part_one" => 1,
+            "part_two" => 2,
+            x => return Err(format!("unknown part \"{}\" for day {}", x, day_key)),
+        };
+        let answer = match answer {
+            Value::String(x) => x.to_owned(),
+            Value::Integer(x) => x.to_string(),
+            x => return Err(format!("unsupported answer {} for day {}", x, day_key)),
+        };
+        answers.insert((year, day, part), answer);
+    }
+
+    Ok(())
+}
+
+/// The answers in `answers.toml`, read once. A missing file counts as no answers.
+pub fn load() -> &'static Answers {
+    static ANSWERS: OnceLock<Answers> = OnceLock::new();
+
+    ANSWERS.get_or_init(
+        || match fs::read_to_string(crate_root().join(ANSWERS_FILE)) {
+            Ok(content) => match Answers::parse(&content) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("Failed to read {}: {}", ANSWERS_FILE, e);
+                    process::exit(1);
+                }
+            },
+            Err(_) => Answers::default(),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict() {
+        let answers = Answers::parse(
+            "[01]\npart_one = 24000\n\n[5]\npart_two = \"MCD\"\n\n[2023.01]\npart_one = 142\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.verdict(2022, 1, 1, Some("24000")), Verdict::Correct);
+        assert_eq!(answers.verdict(2022, 1, 1, Some("24001")), Verdict::Wrong);
+        assert_eq!(answers.verdict(2022, 1, 1, None), Verdict::Wrong);
+        assert_eq!(answers.verdict(2022, 1, 2, Some("45000")), Verdict::Unknown);
+        assert_eq!(answers.verdict(2022, 5, 2, Some("MCD")), Verdict::Correct);
+        assert_eq!(answers.verdict(2023, 1, 1, Some("142")), Verdict::Correct);
+        assert_eq!(answers.verdict(2023, 1, 1, Some("24000")), Verdict::Wrong);
+
+        assert!(Answers::parse("[01]\npart_three = 1\n").is_err());
+    }
+}
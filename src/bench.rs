@@ -0,0 +1,118 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long `--bench` samples each part.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BenchConfig {
+    /// `--runs N`: exactly N timed runs.
+    Runs(u32),
+    /// `--budget MS`: as many timed runs as fit the budget, at least one.
+    Budget(Duration),
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig::Budget(Duration::from_secs(1))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|x| x.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Stats {
+            runs: len as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+/// Warms up `func`, then samples it according to `config`.
+/// Only call this for parts that already solved once: panics are not caught here.
+pub fn bench<R>(config: BenchConfig, func: impl Fn(&str) -> R, input: &str) -> Stats {
+    let mut samples: Vec<Duration> = Vec::new();
+
+    let (warmup_runs, warmup_budget) = match config {
+        BenchConfig::Runs(runs) => (runs.div_ceil(10), Duration::MAX),
+        BenchConfig::Budget(budget) => (u32::MAX, budget / 10),
+    };
+
+    let warmup = Instant::now();
+    for _ in 0..warmup_runs {
+        black_box(func(black_box(input)));
+        if warmup.elapsed() >= warmup_budget {
+            break;
+        }
+    }
+
+    let start = Instant::now();
+    loop {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+
+        let done = match config {
+            BenchConfig::Runs(runs) => samples.len() as u32 >= runs,
+            BenchConfig::Budget(budget) => start.elapsed() >= budget,
+        };
+        if done {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 2, 8, 6]
+            .iter()
+            .map(|x| Duration::from_nanos(*x))
+            .collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+    }
+
+    #[test]
+    fn test_bench_runs() {
+        let stats = bench(BenchConfig::Runs(7), |input: &str| Some(input.len()), "abc");
+        assert_eq!(stats.runs, 7);
+    }
+}
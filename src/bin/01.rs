@@ -1,30 +1,41 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    input
-        .split("\n\n")
-        .map(|x| x.split('\n').map(|y| y.parse::<u32>().unwrap_or(0)).sum())
-        .max()
+/// The calories each elf carries, one list per blank line separated group.
+fn parse_elves(input: &str) -> Result<Vec<Vec<u32>>, AocError> {
+    let mut elves: Vec<Vec<u32>> = vec![vec![]];
+
+    for (idx, line) in input.lines().enumerate() {
+        match line {
+            "" => elves.push(vec![]),
+            x => elves.last_mut().unwrap().push(
+                x.parse::<u32>()
+                    .map_err(|_| AocError::at_line(idx + 1, format!("Bad Calories {}", x)))?,
+            ),
+        }
+    }
+    Ok(elves)
+}
+
+/// Summed as `u64`, as a few elves carrying close to `u32::MAX` calories would overflow a `u32`.
+fn total(calories: &[u32]) -> u64 {
+    calories.iter().map(|x| *x as u64).sum()
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .split("\n\n")
-            .map(|x| {
-                x.split('\n')
-                    .map(|y| y.parse::<u32>().unwrap_or(0))
-                    .sum::<u32>()
-            })
-            .sorted()
-            .rev()
-            .take(3)
-            .sum(),
-    )
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    let elves = parse_elves(input)?;
+    Ok(elves.iter().map(|x| total(x)).max().unwrap_or(0))
 }
 
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    let elves = parse_elves(input)?;
+    Ok(elves.iter().map(|x| total(x)).sorted().rev().take(3).sum())
+}
+
+advent_of_code::solution!(1, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_input(1);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -32,16 +43,41 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(24000));
+        assert_eq!(part_one(&input), Ok(24000));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(45000));
+        assert_eq!(part_two(&input), Ok(45000));
+    }
+
+    /// The calories of every elf, printed back the way the puzzle lists them.
+    struct Elves(Vec<Vec<u32>>);
+
+    impl Display for Elves {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let elves: Vec<String> = self.0.iter().map(|x| x.iter().join("\n")).collect();
+            write!(f, "{}", elves.join("\n\n"))
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(1, 1);
+        advent_of_code::fuzz::assert_round_trip(&[input.trim_end()], |x| parse_elves(x).map(Elves));
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(1, seed),
+            part_two,
+        );
     }
 }
@@ -1,3 +1,6 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::AocError;
+
 #[derive(Debug)]
 enum Action {
     Win,
@@ -14,12 +17,12 @@ impl Action {
         }
     }
 
-    fn from_char(input: char) -> Action {
+    fn from_char(input: char) -> Result<Action, String> {
         match input {
-            'X' => Action::Lose,
-            'Y' => Action::Tie,
-            'Z' => Action::Win,
-            _ => panic!("Bad Action: {input}"),
+            'X' => Ok(Action::Lose),
+            'Y' => Ok(Action::Tie),
+            'Z' => Ok(Action::Win),
+            _ => Err(format!("Bad Action: {input}")),
         }
     }
 }
@@ -32,12 +35,12 @@ enum Move {
 }
 
 impl Move {
-    fn from_char(input: char) -> Move {
+    fn from_char(input: char) -> Result<Move, String> {
         match input {
-            'A' | 'X' => Move::Rock,
-            'B' | 'Y' => Move::Paper,
-            'C' | 'Z' => Move::Scissors,
-            _ => panic!("Bad Move: {input}"),
+            'A' | 'X' => Ok(Move::Rock),
+            'B' | 'Y' => Ok(Move::Paper),
+            'C' | 'Z' => Ok(Move::Scissors),
+            _ => Err(format!("Bad Move: {input}")),
         }
     }
 
@@ -84,22 +87,22 @@ fn score_round(moves: &(Move, Move)) -> (u32, u32) {
     }
 }
 
-fn parse_line_part1(line: &str) -> (Move, Move) {
-    if line.len() != 3 {
-        panic!("Bad Line: {line}");
+fn split_line(line: &str) -> Result<(char, char), String> {
+    match line.chars().collect::<Vec<char>>()[..] {
+        [p1, ' ', p2] => Ok((p1, p2)),
+        _ => Err(format!("Bad Line: {line}")),
     }
-    (
-        Move::from_char(line.chars().next().unwrap()),
-        Move::from_char(line.chars().nth(2).unwrap()),
-    )
 }
 
-fn parse_line_part2(line: &str) -> (Move, Move) {
-    if line.len() != 3 {
-        panic!("Bad Line: {line}");
-    }
-    let p1_move = Move::from_char(line.chars().next().unwrap());
-    let p2_action = Action::from_char(line.chars().nth(2).unwrap());
+fn parse_line_part1(line: &str) -> Result<(Move, Move), String> {
+    let (p1, p2) = split_line(line)?;
+    Ok((Move::from_char(p1)?, Move::from_char(p2)?))
+}
+
+fn parse_line_part2(line: &str) -> Result<(Move, Move), String> {
+    let (p1, p2) = split_line(line)?;
+    let p1_move = Move::from_char(p1)?;
+    let p2_action = Action::from_char(p2)?;
 
     let p2_move: Move = match p2_action {
         Action::Win => match p1_move {
@@ -115,31 +118,33 @@ fn parse_line_part2(line: &str) -> (Move, Move) {
         Action::Tie => p1_move,
     };
 
-    (p1_move, p2_move)
+    Ok((p1_move, p2_move))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let moves: Vec<(Move, Move)> = input.lines().map(parse_line_part1).collect();
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let moves: Vec<(Move, Move)> = parse_lines(input, parse_line_part1)?;
 
     let scores: Vec<(u32, u32)> = moves.iter().map(score_round).collect();
 
     let p2_score = scores.iter().map(|&(.., x)| x).sum();
 
-    Some(p2_score)
+    Ok(p2_score)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let moves: Vec<(Move, Move)> = input.lines().map(parse_line_part2).collect();
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let moves: Vec<(Move, Move)> = parse_lines(input, parse_line_part2)?;
 
     let scores: Vec<(u32, u32)> = moves.iter().map(score_round).collect();
 
     let p2_score = scores.iter().map(|&(.., x)| x).sum();
 
-    Some(p2_score)
+    Ok(p2_score)
 }
 
+advent_of_code::solution!(2, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::read_input(2);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -147,16 +152,53 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        assert_eq!(part_one(&input), Ok(15));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+        assert_eq!(part_two(&input), Ok(12));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let error = part_one("A Y\nB Q\nC Z").unwrap_err();
+        assert_eq!(error, AocError::at_line(2, "Bad Move: Q"));
+
+        let error = part_two("A Y\nB  Z").unwrap_err();
+        assert_eq!(error, AocError::at_line(2, "Bad Line: B  Z"));
+    }
+
+    /// A part one round, printed back the way the strategy guide writes it.
+    struct Round(Move, Move);
+
+    impl Display for Round {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let letter = |first: u8, x: &Move| (first + x.score() as u8 - 1) as char;
+            write!(f, "{} {}", letter(b'A', &self.0), letter(b'X', &self.1))
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(2, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, |x| {
+            parse_line_part1(x).map(|(p1, p2)| Round(p1, p2))
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(2, seed),
+            part_two,
+        );
     }
 }
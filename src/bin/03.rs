@@ -1,3 +1,5 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::AocError;
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -6,24 +8,30 @@ pub struct Rucksack {
 }
 
 impl Rucksack {
-    pub fn common_item(&self) -> Option<char> {
+    pub fn common_item(&self) -> Result<char, String> {
         let compartment_size = self.items.len() / 2;
         let left_compartment: HashSet<_> = self.items.iter().take(compartment_size).collect();
         let right_compartment: HashSet<_> = self.items.iter().skip(compartment_size).collect();
 
         let common: HashSet<_> = left_compartment.intersection(&right_compartment).collect();
         return match common.len() {
-            0 => None,
-            1 => Some(***common.iter().next().unwrap()),
-            _ => panic!("Multiple common items"),
+            0 => Err(String::from("No Common Item")),
+            1 => Ok(***common.iter().next().unwrap()),
+            _ => Err(String::from("Multiple Common Items")),
         };
     }
 }
 
 impl FromStr for Rucksack {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some(item) = input.chars().find(|x| !x.is_ascii_alphabetic()) {
+            return Err(format!("Bad Item {:?}", item));
+        }
+        if !input.len().is_multiple_of(2) {
+            return Err(format!("Uneven Compartments {}", input));
+        }
         Ok(Rucksack {
             items: input.chars().collect(),
         })
@@ -45,7 +53,7 @@ pub fn priority(item: char) -> u32 {
     item as u32 + 1 - 97
 }
 
-pub fn find_badge(sacks: &[Rucksack]) -> Option<char> {
+pub fn find_badge(sacks: &[Rucksack]) -> Result<char, String> {
     let mut items: HashSet<char> = sacks.first().unwrap().into();
 
     for sack in sacks.iter().skip(1) {
@@ -53,40 +61,46 @@ pub fn find_badge(sacks: &[Rucksack]) -> Option<char> {
     }
 
     return match items.len() {
-        0 => None,
-        1 => Some(*items.iter().next().unwrap()),
-        _ => panic!("Multiple common items"),
+        0 => Err(String::from("No Badge")),
+        1 => Ok(*items.iter().next().unwrap()),
+        _ => Err(String::from("Multiple Badges")),
     };
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    return Some(
-        input
-            .lines()
-            .map(|x| Rucksack::from_str(x).unwrap())
-            .map(|x| x.common_item().expect("Error: No Common Item"))
-            .map(priority)
-            .sum(),
-    );
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let rucksacks = parse_lines(input, Rucksack::from_str)?;
+    return rucksacks
+        .iter()
+        .enumerate()
+        .map(|(idx, x)| {
+            x.common_item()
+                .map(priority)
+                .map_err(|e| AocError::at_line(idx + 1, e))
+        })
+        .sum();
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let rucksacks: Vec<Rucksack> = input
-        .lines()
-        .map(|x| Rucksack::from_str(x).unwrap())
-        .collect();
-    Some(
-        rucksacks
-            .as_slice()
-            .chunks(3)
-            .map(|x| find_badge(x).expect("Error: Badge Not Found"))
-            .map(priority)
-            .sum(),
-    )
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let rucksacks = parse_lines(input, Rucksack::from_str)?;
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(AocError::new("Incomplete Group"));
+    }
+    rucksacks
+        .as_slice()
+        .chunks(3)
+        .enumerate()
+        .map(|(idx, x)| {
+            find_badge(x)
+                .map(priority)
+                .map_err(|e| AocError::at_line(idx * 3 + 1, e))
+        })
+        .sum()
 }
 
+advent_of_code::solution!(3, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
+    let input = &advent_of_code::read_input(3);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -94,16 +108,38 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+        assert_eq!(part_one(&input), Ok(157));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+        assert_eq!(part_two(&input), Ok(70));
+    }
+
+    impl Display for Rucksack {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.items.iter().collect::<String>())
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(3, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, Rucksack::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(3, seed),
+            part_two,
+        );
     }
 }
@@ -1,19 +1,23 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::AocError;
 use std::ops::Range;
 
-fn parse_pair(pair: &str) -> Range<u32> {
-    let mut iter = pair.split('-');
-    Range {
-        start: iter.next().unwrap().parse::<u32>().unwrap(),
-        end: iter.next().unwrap().parse::<u32>().unwrap(),
-    }
+fn parse_pair(pair: &str) -> Result<Range<u32>, String> {
+    let (start, end) = pair
+        .split_once('-')
+        .ok_or_else(|| format!("Bad Range {}", pair))?;
+    let section = |x: &str| x.parse::<u32>().map_err(|_| format!("Bad Section {}", x));
+    Ok(Range {
+        start: section(start)?,
+        end: section(end)?,
+    })
 }
 
-fn parse_line(input: &str) -> (Range<u32>, Range<u32>) {
-    let mut iter = input.split(',');
-    (
-        parse_pair(iter.next().unwrap()),
-        parse_pair(iter.next().unwrap()),
-    )
+fn parse_line(input: &str) -> Result<(Range<u32>, Range<u32>), String> {
+    let (p1, p2) = input
+        .split_once(',')
+        .ok_or_else(|| format!("Bad Pair {}", input))?;
+    Ok((parse_pair(p1)?, parse_pair(p2)?))
 }
 
 fn has_full_overlap(pairs: &(Range<u32>, Range<u32>)) -> bool {
@@ -53,28 +57,20 @@ fn has_partial_overlap(pairs: &(Range<u32>, Range<u32>)) -> bool {
     false
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(parse_line)
-            .filter(has_full_overlap)
-            .count() as u32,
-    )
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let pairs = parse_lines(input, parse_line)?;
+    Ok(pairs.iter().filter(|x| has_full_overlap(x)).count() as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(parse_line)
-            .filter(has_partial_overlap)
-            .count() as u32,
-    )
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let pairs = parse_lines(input, parse_line)?;
+    Ok(pairs.iter().filter(|x| has_partial_overlap(x)).count() as u32)
 }
 
+advent_of_code::solution!(4, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
+    let input = &advent_of_code::read_input(4);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -82,16 +78,44 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
+        assert_eq!(part_one(&input), Ok(2));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_two(&input), Some(4));
+        assert_eq!(part_two(&input), Ok(4));
+    }
+
+    /// A pair of assignments, printed back the way the puzzle writes it.
+    struct Pair(Range<u32>, Range<u32>);
+
+    impl Display for Pair {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (p1, p2) = (&self.0, &self.1);
+            write!(f, "{}-{},{}-{}", p1.start, p1.end, p2.start, p2.end)
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(4, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, |x| {
+            parse_line(x).map(|(p1, p2)| Pair(p1, p2))
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(4, seed),
+            part_two,
+        );
     }
 }
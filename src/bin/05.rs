@@ -1,5 +1,6 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
-use std::borrow::BorrowMut;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 struct Action {
@@ -9,47 +10,49 @@ struct Action {
 }
 
 impl FromStr for Action {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut word_iter = input.split(' ');
-
-        let count = word_iter
-            .borrow_mut()
-            .nth(1)
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        let src_idx = word_iter
-            .borrow_mut()
-            .nth(1)
-            .unwrap()
-            .parse::<usize>()
-            .unwrap()
-            - 1;
-
-        let dst_idx = word_iter
-            .borrow_mut()
-            .nth(1)
-            .unwrap()
-            .parse::<usize>()
-            .unwrap()
-            - 1;
+        let words: Vec<&str> = input.split(' ').collect();
+        if words.len() != 6 || words[0] != "move" || words[2] != "from" || words[4] != "to" {
+            return Err(format!("Bad Action {}", input));
+        }
+        let number = |word: &str| {
+            word.parse::<usize>()
+                .map_err(|_| format!("Bad Number {}", word))
+        };
+        let stack_idx = |word: &str| {
+            number(word)?
+                .checked_sub(1)
+                .ok_or_else(|| format!("Bad Stack {}", word))
+        };
 
         Ok(Action {
-            count,
-            src_idx,
-            dst_idx,
+            count: number(words[1])?,
+            src_idx: stack_idx(words[3])?,
+            dst_idx: stack_idx(words[5])?,
         })
     }
 }
-pub fn parse_stacks(input: Vec<&str>) -> Vec<Vec<char>> {
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.src_idx + 1,
+            self.dst_idx + 1
+        )
+    }
+}
+
+pub fn parse_stacks(input: Vec<&str>) -> Result<Vec<Vec<char>>, String> {
     let stack_count = input
         .iter()
         .rev()
         .next()
-        .unwrap()
+        .ok_or("No Stacks")?
         .chars()
         .filter(|x| x == &'[')
         .count();
@@ -61,83 +64,93 @@ pub fn parse_stacks(input: Vec<&str>) -> Vec<Vec<char>> {
 
     for line in input.iter().rev() {
         for (idx, mut chunk) in line.chars().chunks(4).into_iter().enumerate() {
-            let name = chunk.nth(1).unwrap();
+            let name = chunk
+                .nth(1)
+                .ok_or_else(|| format!("Truncated Crate {}", line))?;
             if name != ' ' {
-                stacks.get_mut(idx).unwrap().push(name);
+                stacks
+                    .get_mut(idx)
+                    .ok_or_else(|| format!("Crate Without Stack {}", line))?
+                    .push(name);
             }
         }
     }
 
-    stacks
+    Ok(stacks)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Action>), AocError> {
     let stacks_input = input
         .lines()
         .take_while(|x| x.contains('['))
         .collect::<Vec<_>>();
-    let actions_input: Vec<&str> = input
+    let skipped = stacks_input.len() + 2;
+
+    let stacks = parse_stacks(stacks_input).map_err(AocError::new)?;
+    let actions: Vec<Action> = input
         .lines()
-        .skip(stacks_input.len() + 2)
-        .collect::<Vec<_>>();
+        .enumerate()
+        .skip(skipped)
+        .map(|(idx, line)| Action::from_str(line).map_err(|e| AocError::at_line(idx + 1, e)))
+        .collect::<Result<_, _>>()?;
+
+    Ok((stacks, actions))
+}
 
-    let mut stacks = parse_stacks(stacks_input);
-    let actions: Vec<Action> = actions_input
+fn stack(stacks: &mut [Vec<char>], idx: usize) -> Result<&mut Vec<char>, AocError> {
+    stacks
+        .get_mut(idx)
+        .ok_or_else(|| AocError::new(format!("No Stack {}", idx + 1)))
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> Result<String, AocError> {
+    stacks
         .iter()
-        .map(|x| Action::from_str(x).unwrap())
-        .collect::<Vec<_>>();
+        .enumerate()
+        .map(|(idx, stack)| {
+            stack
+                .last()
+                .copied()
+                .ok_or_else(|| AocError::new(format!("Empty Stack {}", idx + 1)))
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let (mut stacks, actions) = parse(input)?;
 
     for action in actions {
         for _ in 0..action.count {
-            let value = stacks.get_mut(action.src_idx).unwrap().pop().unwrap();
-            stacks.get_mut(action.dst_idx).unwrap().push(value);
+            let value = stack(&mut stacks, action.src_idx)?
+                .pop()
+                .ok_or_else(|| AocError::new(format!("Not Enough Crates: {}", action)))?;
+            stack(&mut stacks, action.dst_idx)?.push(value);
         }
     }
 
-    Some(
-        stacks
-            .iter()
-            .map(|stack| stack.iter().rev().next().copied().unwrap())
-            .collect::<String>(),
-    )
+    top_crates(&stacks)
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let stacks_input = input
-        .lines()
-        .take_while(|x| x.contains('['))
-        .collect::<Vec<_>>();
-    let actions_input: Vec<&str> = input
-        .lines()
-        .skip(stacks_input.len() + 2)
-        .collect::<Vec<_>>();
-
-    let mut stacks = parse_stacks(stacks_input);
-    let actions: Vec<Action> = actions_input
-        .iter()
-        .map(|x| Action::from_str(x).unwrap())
-        .collect::<Vec<_>>();
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let (mut stacks, actions) = parse(input)?;
 
     for action in actions {
-        let new_len = stacks.get_mut(action.src_idx).unwrap().len() - action.count;
-        let mut values: Vec<_> = stacks
-            .get_mut(action.src_idx)
-            .unwrap()
-            .drain(new_len..)
-            .collect();
-        stacks.get_mut(action.dst_idx).unwrap().append(&mut values);
+        let src = stack(&mut stacks, action.src_idx)?;
+        let new_len = src
+            .len()
+            .checked_sub(action.count)
+            .ok_or_else(|| AocError::new(format!("Not Enough Crates: {}", action)))?;
+        let mut values: Vec<_> = src.drain(new_len..).collect();
+        stack(&mut stacks, action.dst_idx)?.append(&mut values);
     }
 
-    Some(
-        stacks
-            .iter()
-            .map(|stack| stack.iter().rev().next().copied().unwrap())
-            .collect::<String>(),
-    )
+    top_crates(&stacks)
 }
 
+advent_of_code::solution!(5, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
+    let input = &advent_of_code::read_input(5);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -149,12 +162,56 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some(String::from("CMZ")));
+        assert_eq!(part_one(&input), Ok(String::from("CMZ")));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some(String::from("MCD")));
+        assert_eq!(part_two(&input), Ok(String::from("MCD")));
+    }
+
+    /// The crates drawing, printed back the way the puzzle draws it.
+    struct Drawing(Vec<Vec<char>>);
+
+    impl Display for Drawing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+            let rows: Vec<String> = (0..height)
+                .rev()
+                .map(|level| {
+                    let row: Vec<String> = self
+                        .0
+                        .iter()
+                        .map(|stack| match stack.get(level) {
+                            Some(name) => format!("[{}]", name),
+                            None => String::from("   "),
+                        })
+                        .collect();
+                    row.join(" ").trim_end().to_owned()
+                })
+                .collect();
+            write!(f, "{}", rows.join("\n"))
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(5, 1);
+        let (drawing, actions) = input.split_once("\n 1").unwrap();
+        advent_of_code::fuzz::assert_round_trip(&[drawing], |x| {
+            parse_stacks(x.lines().collect()).map(Drawing)
+        });
+
+        let actions: Vec<&str> = actions.lines().skip(2).collect();
+        advent_of_code::fuzz::assert_round_trip(&actions, Action::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(5, seed),
+            part_two,
+        );
     }
 }
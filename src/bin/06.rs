@@ -1,29 +1,43 @@
+use advent_of_code::AocError;
 use std::collections::HashSet;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let chars: Vec<char> = input.chars().collect();
+fn parse_signal(input: &str) -> Result<Vec<char>, AocError> {
+    input
+        .trim_end()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' => Ok(c),
+            _ => Err(AocError::new(format!("Unexpected {:?}", c))),
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let chars = parse_signal(input)?;
 
     for (idx, window) in chars.windows(4).enumerate() {
         if HashSet::<char>::from_iter(window.iter().cloned()).len() == 4 {
-            return Some((idx + 4) as _);
+            return Ok((idx + 4) as _);
         }
     }
-    None
+    Err(AocError::new("No Marker"))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let chars: Vec<char> = input.chars().collect();
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let chars = parse_signal(input)?;
 
     for (idx, window) in chars.windows(14).enumerate() {
         if HashSet::<char>::from_iter(window.iter().cloned()).len() == 14 {
-            return Some((idx + 14) as _);
+            return Ok((idx + 14) as _);
         }
     }
-    None
+    Err(AocError::new("No Marker"))
 }
 
+advent_of_code::solution!(6, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
+    let input = &advent_of_code::read_input(6);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -35,12 +49,28 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(7));
+        assert_eq!(part_one(&input), Ok(7));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(19));
+        assert_eq!(part_two(&input), Ok(19));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(6, 1);
+        advent_of_code::fuzz::assert_round_trip(&[input.trim_end()], |x| {
+            parse_signal(x).map(|x| x.iter().collect::<String>())
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(6, seed),
+            part_two,
+        );
     }
 }
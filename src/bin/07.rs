@@ -1,4 +1,6 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
+use std::fmt::{self, Display};
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -8,7 +10,7 @@ enum PathKind {
 }
 
 #[derive(Clone, Debug)]
-struct PathItem {
+pub struct PathItem {
     kind: PathKind,
     size: usize,
     name: String,
@@ -42,52 +44,66 @@ impl PathItem {
     }
 }
 
+#[derive(Debug, PartialEq)]
 enum Cmd {
     List,
     Cd(String),
 }
 
 impl Cmd {
-    pub fn parse(line: &str) -> Cmd {
+    pub fn parse(line: &str) -> Result<Cmd, String> {
         if line == "$ ls" {
-            return Cmd::List;
+            return Ok(Cmd::List);
+        }
+        match line.strip_prefix("$ cd ") {
+            Some(dir) if !dir.is_empty() => Ok(Cmd::Cd(dir.to_owned())),
+            _ => Err(format!("Bad Command {}", line)),
         }
-        Cmd::Cd(line.split(' ').last().unwrap().to_owned())
     }
 }
 
-fn parse_ls(cwd: &str, ls_entries: Vec<&str>) -> Vec<PathItem> {
-    let mut fs_items: Vec<PathItem> = Vec::new();
-
-    for entry in ls_entries {
-        if entry.starts_with("dir") {
-            let dirname = entry.split(' ').last().unwrap();
-            fs_items.push(PathItem::dir(dirname, cwd));
-            continue;
+impl Display for Cmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cmd::List => write!(f, "$ ls"),
+            Cmd::Cd(dir) => write!(f, "$ cd {}", dir),
         }
+    }
+}
 
-        // else file
-        let name = entry.split(' ').last().unwrap();
-        let size = entry.split(' ').next().unwrap().parse::<usize>().unwrap();
-        fs_items.push(PathItem::file(name, cwd, size));
+fn parse_entry(cwd: &str, entry: &str) -> Result<PathItem, String> {
+    match entry.split_once(' ') {
+        Some(("dir", name)) => Ok(PathItem::dir(name, cwd)),
+        Some((size, name)) => size
+            .parse::<usize>()
+            .map(|size| PathItem::file(name, cwd, size))
+            .map_err(|_| format!("Bad Size {}", size)),
+        None => Err(format!("Bad Entry {}", entry)),
     }
-    fs_items
 }
 
-fn build_filesystem(input: &str) -> Vec<PathItem> {
+pub fn parse(input: &str) -> Result<Vec<PathItem>, AocError> {
     let mut filesystem: Vec<PathItem> = Vec::from([PathItem::dir("/", "/")]);
-    let mut iter = input.lines().skip(1);
+    let mut iter = input.lines().enumerate().map(|(idx, line)| (idx + 1, line));
     let mut cwd = PathBuf::from("/");
 
-    while let Some(cmd_str) = iter.next() {
-        match Cmd::parse(cmd_str) {
+    while let Some((line, cmd_str)) = iter.next() {
+        match Cmd::parse(cmd_str).map_err(|e| AocError::at_line(line, e))? {
             Cmd::List => {
-                let ls_entries = iter.take_while_ref(|x| !x.starts_with('$')).collect();
-                filesystem.append(&mut parse_ls(cwd.to_str().unwrap(), ls_entries));
+                for (line, entry) in iter.take_while_ref(|(_, x)| !x.starts_with('$')) {
+                    let item = parse_entry(cwd.to_str().unwrap(), entry)
+                        .map_err(|e| AocError::at_line(line, e))?;
+                    filesystem.push(item);
+                }
             }
             Cmd::Cd(x) => match x.as_str() {
                 "/" => cwd = PathBuf::from("/"),
-                ".." => cwd = cwd.parent().unwrap().to_owned(),
+                ".." => {
+                    cwd = cwd
+                        .parent()
+                        .ok_or_else(|| AocError::at_line(line, "Cannot Leave /"))?
+                        .to_owned()
+                }
                 _ => cwd.push(x),
             },
         }
@@ -118,16 +134,14 @@ fn build_filesystem(input: &str) -> Vec<PathItem> {
                     fullpath.push(&x.name);
                     x.kind == PathKind::Directory && (fullpath == path)
                 })
-                .unwrap()
+                .ok_or_else(|| AocError::new(format!("Unlisted Directory {}", path.display())))?
                 .size = size;
         }
     }
-    filesystem
+    Ok(filesystem)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let filesystem = build_filesystem(input);
-
+pub fn part_one(filesystem: &[PathItem]) -> Option<u32> {
     let total_size: usize = filesystem
         .iter()
         .filter(|x| x.kind == PathKind::Directory)
@@ -138,19 +152,18 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(total_size as _)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let filesystem = build_filesystem(input);
-    let total_space = 70000000;
-    let space_target = 30000000;
+pub fn part_two(filesystem: &[PathItem]) -> Result<u32, AocError> {
+    let total_space: usize = 70000000;
+    let space_target: usize = 30000000;
 
     let root_size = filesystem
         .iter()
-        .find(|x| x.path.to_str().unwrap() == "/" && x.name == "/")
-        .unwrap()
+        .find(|x| x.path.to_str() == Some("/") && x.name == "/")
+        .ok_or_else(|| AocError::new("No Root Directory"))?
         .size;
 
-    let free_space = total_space - root_size;
-    let needed_space = space_target - free_space;
+    let free_space = total_space.saturating_sub(root_size);
+    let needed_space = space_target.saturating_sub(free_space);
 
     let smallest_size = filesystem
         .iter()
@@ -158,15 +171,16 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|x| x.size)
         .filter(|x| x > &needed_space)
         .min()
-        .unwrap();
+        .ok_or_else(|| AocError::new("No Directory Frees Enough Space"))?;
 
-    Some(smallest_size as _)
+    Ok(smallest_size as _)
 }
 
+advent_of_code::solution!(7, parse: parse, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(7);
+    advent_of_code::solve!(parse, part_one, part_two, input);
 }
 
 #[cfg(test)]
@@ -176,12 +190,27 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(24933642));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(7, 1);
+        let commands: Vec<&str> = input.lines().filter(|x| x.starts_with('$')).collect();
+        advent_of_code::fuzz::assert_round_trip(&commands, Cmd::parse);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(7, seed),
+            |input| parse(input).map(|x| part_two(&x)),
+        );
     }
 }
@@ -1,150 +1,60 @@
-fn is_edge(x: usize, y: usize, width: usize, height: usize) -> bool {
-    x == 0 || y == 0 || x == (width - 1) || y == (height - 1)
-}
-
-fn is_visible(x: usize, y: usize, width: usize, height: usize, trees: &[usize]) -> bool {
-    let tree_height = trees.get(y * width + x).unwrap();
-
-    // Row Check Right
-    for w in (x + 1)..width {
-        if trees.get((y * width) + w).unwrap() >= tree_height {
-            break;
-        }
-        if w == (width - 1) {
-            return true;
-        }
-    }
-
-    // Row Check Left
-    for w in 0..x {
-        if trees.get((y * width) + w).unwrap() >= tree_height {
-            break;
-        }
-        if w == (x - 1) {
-            return true;
-        }
-    }
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::AocError;
 
-    // Col Check Down
-    for z in (y + 1)..height {
-        if trees.get((z * width) + x).unwrap() >= tree_height {
-            break;
-        }
-        if z == (height - 1) {
-            return true;
-        }
-    }
-
-    // Col Check Up
-    for z in 0..y {
-        if trees.get((z * width) + x).unwrap() >= tree_height {
-            break;
-        }
-        if z == (y - 1) {
-            return true;
-        }
-    }
-    false
+fn is_clear<'a>(mut line: impl Iterator<Item = &'a u32>, tree_height: u32) -> bool {
+    line.all(|x| *x < tree_height)
 }
 
-fn scenic_score(x: usize, y: usize, width: usize, height: usize, trees: &[usize]) -> u32 {
-    if is_edge(x, y, width, height) {
-        return 0;
-    }
-    let tree_height = trees.get(y * width + x).unwrap();
-
-    // Left Score
-    let mut lscore: u32 = 0;
-    for w in (0..x).rev() {
-        lscore += 1;
-        if trees.get((y * width) + w).unwrap() >= tree_height {
-            break;
-        }
-    }
-
-    // Right Score
-    let mut rscore: u32 = 0;
-    for w in (x + 1)..width {
-        rscore += 1;
-        if trees.get((y * width) + w).unwrap() >= tree_height {
-            break;
-        }
-    }
-
-    // Up Score
-    let mut uscore: u32 = 0;
-    for z in (0..y).rev() {
-        uscore += 1;
-        if trees.get((z * width) + x).unwrap() >= tree_height {
-            break;
-        }
-    }
-
-    // Down Score
-    let mut dscore: u32 = 0;
-    for z in (y + 1)..height {
-        dscore += 1;
-        if trees.get((z * width) + x).unwrap() >= tree_height {
+/// Counts up to and including the first tree at least as tall as `tree_height`.
+fn viewing_distance<'a>(line: impl Iterator<Item = &'a u32>, tree_height: u32) -> u32 {
+    let mut distance = 0;
+    for x in line {
+        distance += 1;
+        if *x >= tree_height {
             break;
         }
     }
-
-    lscore * rscore * uscore * dscore
+    distance
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
+fn is_visible(trees: &Grid<u32>, point: &Point<usize>) -> bool {
+    let tree_height = trees[point];
+    let row = trees.row(point.y);
 
-    let trees: Vec<usize> = input
-        .chars()
-        .filter(|x| *x != '\n')
-        .map(|x| x.to_digit(10).unwrap() as usize)
-        .collect();
+    is_clear(row[..point.x].iter(), tree_height)
+        || is_clear(row[point.x + 1..].iter(), tree_height)
+        || is_clear(trees.column(point.x).take(point.y), tree_height)
+        || is_clear(trees.column(point.x).skip(point.y + 1), tree_height)
+}
 
-    let mut visible = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if is_edge(x, y, width, height) {
-                visible += 1;
-                continue;
-            }
-            if is_visible(x, y, width, height, &trees) {
-                visible += 1;
-            }
-        }
-    }
+fn scenic_score(trees: &Grid<u32>, point: &Point<usize>) -> u32 {
+    let tree_height = trees[point];
+    let row = trees.row(point.y);
 
-    Some(visible)
+    viewing_distance(row[..point.x].iter().rev(), tree_height)
+        * viewing_distance(row[point.x + 1..].iter(), tree_height)
+        * viewing_distance(trees.column(point.x).take(point.y).rev(), tree_height)
+        * viewing_distance(trees.column(point.x).skip(point.y + 1), tree_height)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-
-    let trees: Vec<usize> = input
-        .chars()
-        .filter(|x| *x != '\n')
-        .map(|x| x.to_digit(10).unwrap() as usize)
-        .collect();
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let trees = Grid::from_str(input, |x| x.to_digit(10))?;
+    Ok(trees.points().filter(|x| is_visible(&trees, x)).count() as u32)
+}
 
-    Some(
-        (0..height)
-            .into_iter()
-            .map(|y| {
-                (0..width)
-                    .into_iter()
-                    .map(|x| scenic_score(x, y, width, height, &trees))
-                    .max()
-                    .unwrap()
-            })
-            .max()
-            .unwrap(),
-    )
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let trees = Grid::from_str(input, |x| x.to_digit(10))?;
+    Ok(trees
+        .points()
+        .map(|x| scenic_score(&trees, &x))
+        .max()
+        .unwrap_or(0))
 }
 
+advent_of_code::solution!(8, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
+    let input = &advent_of_code::read_input(8);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -156,12 +66,28 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(21));
+        assert_eq!(part_one(&input), Ok(21));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
+        assert_eq!(part_two(&input), Ok(8));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(8, 1);
+        advent_of_code::fuzz::assert_round_trip(&[input.trim_end()], |x| {
+            Grid::from_str(x, |c| c.to_digit(10))
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(8, seed),
+            part_two,
+        );
     }
 }
@@ -1,160 +1,90 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::helpers::{Direction, Point};
+use advent_of_code::AocError;
 use std::collections::HashSet;
 
 const KNOT_COUNT: usize = 10;
 const HEAD: usize = 0;
 const TAIL: usize = KNOT_COUNT - 1_usize;
+/// Far above the puzzle's counts, so a mangled count fails instead of walking the rope for minutes.
+const MAX_COUNT: usize = 10_000;
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn x_dist(&self, other: &Point) -> i32 {
-        (other.x - self.x).abs()
-    }
-
-    fn y_dist(&self, other: &Point) -> i32 {
-        (other.y - self.y).abs()
-    }
-
-    fn adjacent(&self, other: &Point) -> bool {
-        self.x_dist(other) <= 1 && self.y_dist(other) <= 1
+fn parse_direction(value: char) -> Result<Direction, String> {
+    match value {
+        'L' => Ok(Direction::Left),
+        'R' => Ok(Direction::Right),
+        'U' => Ok(Direction::Up),
+        'D' => Ok(Direction::Down),
+        _ => Err(format!("Bad Direction: {}", value)),
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
+fn parse_instruction(line: &str) -> Result<(Direction, usize), String> {
+    let (direction, count) = line
+        .split_once(' ')
+        .ok_or_else(|| format!("Bad Instruction: {}", line))?;
+
+    let mut chars = direction.chars();
+    let direction = match (chars.next(), chars.next()) {
+        (Some(x), None) => parse_direction(x)?,
+        _ => return Err(format!("Bad Direction: {}", direction)),
+    };
+    let count = count
+        .parse::<usize>()
+        .ok()
+        .filter(|x| *x <= MAX_COUNT)
+        .ok_or_else(|| format!("Bad Count: {}", count))?;
+
+    Ok((direction, count))
 }
 
-impl Direction {
-    pub fn from_char(value: char) -> Self {
-        match value {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            'U' => Direction::Up,
-            'D' => Direction::Down,
-            _ => panic!("Bad Direction: {}", value),
-        }
-    }
-
-    pub fn value(&self) -> i32 {
-        match self {
-            Direction::Left | Direction::Down => -1,
-            Direction::Right | Direction::Up => 1,
-        }
-    }
-}
-
-fn parse_instruction(line: &str) -> (Direction, usize) {
-    (
-        Direction::from_char(line.chars().next().unwrap()),
-        line.split(' ').last().unwrap().parse::<usize>().unwrap(),
-    )
-}
-
-fn knot_action(head_pos: &Point, tail_pos: &mut Point) {
-    if tail_pos.adjacent(head_pos) {
-        return;
-    }
-    let x_dist = tail_pos.x_dist(head_pos);
-    let y_dist = tail_pos.y_dist(head_pos);
-
-    if x_dist > 1 && y_dist == 0 {
-        if head_pos.x > tail_pos.x {
-            tail_pos.x += 1;
-        } else {
-            tail_pos.x -= 1;
-        }
-        return;
-    }
-    if y_dist > 1 && x_dist == 0 {
-        if head_pos.y > tail_pos.y {
-            tail_pos.y += 1;
-        } else {
-            tail_pos.y -= 1;
-        }
+fn knot_action(head_pos: &Point<i32>, tail_pos: &mut Point<i32>) {
+    if tail_pos.chebyshev(head_pos) <= 1 {
         return;
     }
+    // one step towards the head, diagonally if it is not in the same row or column.
+    *tail_pos += Point::new(
+        (head_pos.x - tail_pos.x).signum(),
+        (head_pos.y - tail_pos.y).signum(),
+    );
+}
 
-    // Brute force diagonal
-    if head_pos.adjacent(&Point {
-        x: tail_pos.x - 1,
-        y: tail_pos.y - 1,
-    }) {
-        tail_pos.x -= 1;
-        tail_pos.y -= 1;
-        return;
-    }
-    if head_pos.adjacent(&Point {
-        x: tail_pos.x - 1,
-        y: tail_pos.y + 1,
-    }) {
-        tail_pos.x -= 1;
-        tail_pos.y += 1;
-        return;
-    }
-    if head_pos.adjacent(&Point {
-        x: tail_pos.x + 1,
-        y: tail_pos.y - 1,
-    }) {
-        tail_pos.x += 1;
-        tail_pos.y -= 1;
-        return;
-    }
-    if head_pos.adjacent(&Point {
-        x: tail_pos.x + 1,
-        y: tail_pos.y + 1,
-    }) {
-        tail_pos.x += 1;
-        tail_pos.y += 1;
-    }
+fn step(knot: &mut Point<i32>, direction: Direction) -> Result<(), AocError> {
+    *knot = knot
+        .step(direction)
+        .ok_or_else(|| AocError::new("Rope Out Of Range"))?;
+    Ok(())
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut head_pos = Point { x: 0, y: 0 };
-    let mut tail_pos = Point { x: 0, y: 0 };
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let mut head_pos: Point<i32> = Point::default();
+    let mut tail_pos: Point<i32> = Point::default();
 
-    let series = input.lines().map(parse_instruction);
+    let series = parse_lines(input, parse_instruction)?;
 
-    let mut history: HashSet<Point> = HashSet::new();
+    let mut history: HashSet<Point<i32>> = HashSet::new();
 
     for (direction, count) in series {
         for _ in 0..count {
-            match direction {
-                Direction::Left | Direction::Right => head_pos.x += direction.value(),
-                Direction::Up | Direction::Down => head_pos.y += direction.value(),
-            }
+            step(&mut head_pos, direction)?;
             knot_action(&head_pos, &mut tail_pos);
             history.insert(tail_pos);
         }
     }
 
-    Some(history.len() as _)
+    Ok(history.len() as _)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut knots: Vec<Point> = (0..KNOT_COUNT).map(|_| Point::default()).collect();
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let mut knots: Vec<Point<i32>> = (0..KNOT_COUNT).map(|_| Point::default()).collect();
 
-    let series = input.lines().map(parse_instruction);
+    let series = parse_lines(input, parse_instruction)?;
 
-    let mut history: HashSet<Point> = HashSet::new();
+    let mut history: HashSet<Point<i32>> = HashSet::new();
 
     for (direction, count) in series {
         for _ in 0..count {
-            match direction {
-                Direction::Left | Direction::Right => {
-                    knots.get_mut(HEAD).unwrap().x += direction.value()
-                }
-                Direction::Up | Direction::Down => {
-                    knots.get_mut(HEAD).unwrap().y += direction.value()
-                }
-            }
+            step(knots.get_mut(HEAD).unwrap(), direction)?;
 
             for idx in 1..KNOT_COUNT {
                 let prev = *knots.get(idx - 1).unwrap();
@@ -167,11 +97,13 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(history.len() as _)
+    Ok(history.len() as _)
 }
 
+advent_of_code::solution!(9, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
+    let input = &advent_of_code::read_input(9);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -179,16 +111,55 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
+
+    advent_of_code::example_tests!(
+        test_examples,
+        [
+            ("a", part_one, Ok(13)),
+            ("a", part_two, Ok(1)),
+            ("b", part_two, Ok(36)),
+        ]
+    );
+
+    #[test]
+    fn test_bad_input() {
+        let error = part_one("R 4\nX 2").unwrap_err();
+        assert_eq!(error, AocError::at_line(2, "Bad Direction: X"));
+
+        let error = part_two("R 4\nU 4\nL").unwrap_err();
+        assert_eq!(error, AocError::at_line(3, "Bad Instruction: L"));
+    }
+
+    /// A motion, printed back the way the puzzle writes it.
+    struct Motion(Direction, usize);
+
+    impl Display for Motion {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let direction = match self.0 {
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+                Direction::Up => 'U',
+                Direction::Down => 'D',
+            };
+            write!(f, "{} {}", direction, self.1)
+        }
+    }
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 91);
-        assert_eq!(part_one(&input), Some(13));
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(9, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, |x| {
+            parse_instruction(x).map(|(direction, count)| Motion(direction, count))
+        });
     }
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 92);
-        assert_eq!(part_two(&input), Some(36));
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(9, seed),
+            part_two,
+        );
     }
 }
@@ -1,3 +1,6 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::AocError;
+
 struct Cpu {
     register: i32,
     cycles: u32,
@@ -16,17 +19,20 @@ impl Instruction {
         }
     }
 
-    pub fn parse_str(input: &str) -> Self {
+    pub fn parse_str(input: &str) -> Result<Self, String> {
         let mut iter = input.split(' ');
-        match iter.next().unwrap() {
-            "addx" => Self::Add(iter.next().unwrap().parse::<i32>().unwrap()),
-            "noop" => Self::Nop,
-            x => panic!("Bad Instruction: {}", x),
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some("addx"), Some(x), None) => x
+                .parse::<i32>()
+                .map(Self::Add)
+                .map_err(|_| format!("Bad Argument: {}", x)),
+            (Some("noop"), None, None) => Ok(Self::Nop),
+            _ => Err(format!("Bad Instruction: {}", input)),
         }
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, AocError> {
     let mut cpu = Cpu {
         register: 1,
         cycles: 1,
@@ -34,7 +40,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let key_cycles = Vec::from([20, 60, 100, 140, 180, 220]);
     let mut signal_strength: i32 = 0;
 
-    let instructions: Vec<Instruction> = input.lines().map(Instruction::parse_str).collect();
+    let instructions: Vec<Instruction> = parse_lines(input, Instruction::parse_str)?;
 
     for instr in instructions {
         for cycle in cpu.cycles..(cpu.cycles + instr.cost()) {
@@ -49,27 +55,28 @@ pub fn part_one(input: &str) -> Option<u32> {
         cpu.cycles += instr.cost();
     }
 
-    Some(signal_strength as _)
+    Ok(signal_strength as _)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<String, AocError> {
     let mut cpu = Cpu {
         register: 1,
         cycles: 1,
     };
+    let mut screen = String::new();
 
-    let instructions: Vec<Instruction> = input.lines().map(Instruction::parse_str).collect();
+    let instructions: Vec<Instruction> = parse_lines(input, Instruction::parse_str)?;
 
     for instr in instructions {
         for cycle in cpu.cycles..(cpu.cycles + instr.cost()) {
             let pos: i32 = (cycle as i32 - 1) % 40;
-            if pos == 0 {
-                println!()
+            if pos == 0 && !screen.is_empty() {
+                screen.push('\n');
             }
             if (cpu.register as i32 - pos).abs() <= 1 {
-                print!("#");
+                screen.push('#');
             } else {
-                print!(".");
+                screen.push('.');
             }
         }
 
@@ -78,12 +85,13 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
         cpu.cycles += instr.cost();
     }
-    println!();
-    Some(0)
+    Ok(screen)
 }
 
+advent_of_code::solution!(10, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::read_input(10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -91,16 +99,58 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        assert_eq!(part_one(&input), Ok(13140));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), Some(0));
+        let screen = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(part_two(&input), Ok(screen.join("\n")));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let error = part_one("noop\naddx 3\naddx x").unwrap_err();
+        assert_eq!(error, AocError::at_line(3, "Bad Argument: x"));
+
+        let error = part_two("noop\nmul 2").unwrap_err();
+        assert_eq!(error, AocError::at_line(2, "Bad Instruction: mul 2"));
+    }
+
+    impl Display for Instruction {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Instruction::Add(x) => write!(f, "addx {}", x),
+                Instruction::Nop => write!(f, "noop"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(10, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, Instruction::parse_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(10, seed),
+            part_two,
+        );
     }
 }
@@ -1,5 +1,6 @@
-use itertools::{Chunk, Itertools};
-use std::str::Lines;
+use advent_of_code::AocError;
+use itertools::Itertools;
+use std::str::FromStr;
 
 #[derive(Debug)]
 enum Operation {
@@ -14,11 +15,11 @@ struct Action {
 }
 
 impl Operation {
-    pub fn from_str(value: &str) -> Self {
+    pub fn from_str(value: &str) -> Result<Self, String> {
         match value {
-            "+" => Operation::Add,
-            "*" => Operation::Multiply,
-            x => panic!("Bad Operation {}", x),
+            "+" => Ok(Operation::Add),
+            "*" => Ok(Operation::Multiply),
+            x => Err(format!("Bad Operation {}", x)),
         }
     }
 }
@@ -30,10 +31,13 @@ enum Argument {
 }
 
 impl Argument {
-    pub fn from_str(value: &str) -> Self {
+    pub fn from_str(value: &str) -> Result<Self, String> {
         match value {
-            "old" => Argument::OldValue,
-            x => Argument::Literal(x.parse::<u64>().unwrap()),
+            "old" => Ok(Argument::OldValue),
+            x => x
+                .parse::<u64>()
+                .map(Argument::Literal)
+                .map_err(|_| format!("Bad Argument {}", x)),
         }
     }
 }
@@ -46,16 +50,23 @@ struct Expression {
 }
 
 impl Expression {
-    pub fn from_str(line: &str) -> Self {
-        let mut items = line.split("= ").nth(1).unwrap().split(' ');
-        Self {
-            arg1: Argument::from_str(items.next().unwrap()),
-            op: Operation::from_str(items.next().unwrap()),
-            arg2: Argument::from_str(items.next().unwrap()),
+    pub fn from_str(line: &str) -> Result<Self, String> {
+        let expression = line
+            .split("= ")
+            .nth(1)
+            .ok_or_else(|| format!("Bad Expression {}", line.trim()))?;
+        match expression.split(' ').collect::<Vec<&str>>()[..] {
+            [arg1, op, arg2] => Ok(Self {
+                arg1: Argument::from_str(arg1)?,
+                op: Operation::from_str(op)?,
+                arg2: Argument::from_str(arg2)?,
+            }),
+            _ => Err(format!("Bad Expression {}", expression)),
         }
     }
 
-    pub fn evaluate(&self, old_value: u64) -> u64 {
+    /// `None` if the worry level overflows.
+    pub fn evaluate(&self, old_value: u64) -> Option<u64> {
         let arg1 = match self.arg1 {
             Argument::OldValue => old_value,
             Argument::Literal(x) => x,
@@ -65,8 +76,8 @@ impl Expression {
             Argument::Literal(x) => x,
         };
         match self.op {
-            Operation::Add => arg1 + arg2,
-            Operation::Multiply => arg1 * arg2,
+            Operation::Add => arg1.checked_add(arg2),
+            Operation::Multiply => arg1.checked_mul(arg2),
         }
     }
 }
@@ -81,55 +92,60 @@ struct Monkey {
     false_dst: usize,
 }
 
+/// The next line of a monkey block, with its 1-based line number.
+fn next_line<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<(usize, &'a str), AocError> {
+    lines
+        .next()
+        .map(|(idx, line)| (idx + 1, line))
+        .ok_or_else(|| AocError::new("Incomplete Monkey"))
+}
+
+fn parse_last_word<'a, T: FromStr>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<T, AocError> {
+    let (line, text) = next_line(lines)?;
+    let word = text.split(' ').next_back().unwrap_or_default();
+    word.parse::<T>()
+        .map_err(|_| AocError::at_line(line, format!("Bad Number {}", word)))
+}
+
 impl Monkey {
-    pub fn from_lines(mut lines: Chunk<Lines>) -> Self {
-        let items: Vec<u64> = lines
-            .nth(1)
-            .unwrap()
+    pub fn from_lines<'a>(
+        mut lines: impl Iterator<Item = (usize, &'a str)>,
+    ) -> Result<Self, AocError> {
+        next_line(&mut lines)?;
+
+        let (line, text) = next_line(&mut lines)?;
+        let items: Vec<u64> = text
             .split(':')
-            .last()
-            .unwrap()
+            .next_back()
+            .unwrap_or_default()
             .split(',')
-            .map(|x| x.trim().parse::<u64>().unwrap())
-            .collect();
+            .map(|x| x.trim().parse::<u64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| AocError::at_line(line, format!("Bad Items {}", text.trim())))?;
+
+        let (line, text) = next_line(&mut lines)?;
+        let expression = Expression::from_str(text).map_err(|e| AocError::at_line(line, e))?;
+
+        let divisor: u64 = parse_last_word(&mut lines)?;
+        if divisor == 0 {
+            return Err(AocError::at_line(line + 1, "Bad Divisor 0"));
+        }
+
+        let true_dst = parse_last_word(&mut lines)?;
+        let false_dst = parse_last_word(&mut lines)?;
 
-        let expression = Expression::from_str(lines.next().unwrap());
-
-        let divisor = lines
-            .next()
-            .unwrap()
-            .split(' ')
-            .last()
-            .unwrap()
-            .parse::<u64>()
-            .unwrap();
-
-        let true_dst = lines
-            .next()
-            .unwrap()
-            .split(' ')
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        let false_dst = lines
-            .next()
-            .unwrap()
-            .split(' ')
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        Self {
+        Ok(Self {
             items,
             expression,
             divisor,
             true_dst,
             false_dst,
             inspect_count: 0,
-        }
+        })
     }
 
     pub fn test(&self, value: u64) -> usize {
@@ -140,13 +156,28 @@ impl Monkey {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut monkies: Vec<Monkey> = input
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let monkies: Vec<Monkey> = input
         .lines()
+        .enumerate()
         .chunks(7)
         .into_iter()
         .map(Monkey::from_lines)
-        .collect();
+        .collect::<Result<_, _>>()?;
+
+    for (idx, monkey) in monkies.iter().enumerate() {
+        if monkey.true_dst.max(monkey.false_dst) >= monkies.len() {
+            return Err(AocError::new(format!(
+                "Monkey {} throws to a missing monkey",
+                idx
+            )));
+        }
+    }
+    Ok(monkies)
+}
+
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    let mut monkies = parse_monkeys(input)?;
 
     for _ in 0..20 {
         for monkey_idx in 0..monkies.len() {
@@ -157,13 +188,15 @@ pub fn part_one(input: &str) -> Option<u64> {
             let actions = items
                 .iter()
                 .map(|x| {
-                    let new_value = monkey.expression.evaluate(*x) / 3;
-                    Action {
+                    let new_value = monkey.expression.evaluate(*x).ok_or_else(|| {
+                        AocError::new(format!("Worry Level Overflow at Monkey {}", monkey_idx))
+                    })? / 3;
+                    Ok(Action {
                         item: new_value,
                         dst: monkey.test(new_value),
-                    }
+                    })
                 })
-                .collect::<Vec<Action>>();
+                .collect::<Result<Vec<Action>, AocError>>()?;
 
             for action in actions {
                 monkies.get_mut(action.dst).unwrap().items.push(action.item);
@@ -177,18 +210,16 @@ pub fn part_one(input: &str) -> Option<u64> {
         .sorted()
         .collect::<Vec<u64>>();
 
-    Some(counts.iter().rev().take(2).product())
+    Ok(counts.iter().rev().take(2).product())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut monkies: Vec<Monkey> = input
-        .lines()
-        .chunks(7)
-        .into_iter()
-        .map(Monkey::from_lines)
-        .collect();
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    let mut monkies = parse_monkeys(input)?;
 
-    let modulo: u64 = monkies.iter().map(|x| x.divisor).product();
+    let modulo: u64 = monkies
+        .iter()
+        .try_fold(1_u64, |total, x| total.checked_mul(x.divisor))
+        .ok_or_else(|| AocError::new("Divisors Overflow"))?;
 
     for _ in 0..10000 {
         for monkey_idx in 0..monkies.len() {
@@ -199,13 +230,15 @@ pub fn part_two(input: &str) -> Option<u64> {
             let actions = items
                 .iter()
                 .map(|x| {
-                    let new_value = monkey.expression.evaluate(*x) % modulo;
-                    Action {
+                    let new_value = monkey.expression.evaluate(*x).ok_or_else(|| {
+                        AocError::new(format!("Worry Level Overflow at Monkey {}", monkey_idx))
+                    })? % modulo;
+                    Ok(Action {
                         item: new_value,
                         dst: monkey.test(new_value),
-                    }
+                    })
                 })
-                .collect::<Vec<Action>>();
+                .collect::<Result<Vec<Action>, AocError>>()?;
 
             for action in actions {
                 monkies.get_mut(action.dst).unwrap().items.push(action.item);
@@ -219,11 +252,13 @@ pub fn part_two(input: &str) -> Option<u64> {
         .sorted()
         .collect::<Vec<u64>>();
 
-    Some(counts.iter().rev().take(2).product())
+    Ok(counts.iter().rev().take(2).product())
 }
 
+advent_of_code::solution!(11, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
+    let input = &advent_of_code::read_input(11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -231,16 +266,67 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
+        assert_eq!(part_one(&input), Ok(10605));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
+        assert_eq!(part_two(&input), Ok(2713310158));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let input = advent_of_code::read_file("examples", 11);
+
+        let error = part_one(&input.replacen("new = old * 19", "new = old / 19", 1)).unwrap_err();
+        assert_eq!(error, AocError::at_line(3, "Bad Operation /"));
+
+        let error = part_two(&input.replacen("divisible by 13", "divisible by x", 1)).unwrap_err();
+        assert_eq!(error, AocError::at_line(18, "Bad Number x"));
+    }
+
+    impl Display for Expression {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let argument = |x: &Argument| match x {
+                Argument::Literal(x) => x.to_string(),
+                Argument::OldValue => String::from("old"),
+            };
+            let op = match self.op {
+                Operation::Add => '+',
+                Operation::Multiply => '*',
+            };
+            write!(
+                f,
+                "new = {} {} {}",
+                argument(&self.arg1),
+                op,
+                argument(&self.arg2)
+            )
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(11, 1);
+        let expressions: Vec<&str> = input
+            .lines()
+            .filter_map(|x| x.strip_prefix("  Operation: "))
+            .collect();
+        assert!(!expressions.is_empty());
+        advent_of_code::fuzz::assert_round_trip(&expressions, Expression::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(11, seed),
+            part_two,
+        );
     }
 }
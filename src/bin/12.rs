@@ -1,88 +1,10 @@
-use std::cmp::PartialEq;
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::AocError;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fmt::{Debug, Display};
 
 const START: usize = 0;
 const END: usize = ('z' as usize) - ('a' as usize) + 2;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-}
-
-#[derive(Debug)]
-struct Grid<T> {
-    width: usize,
-    height: usize,
-    items: Vec<T>,
-}
-
-impl<T: PartialEq> Grid<T> {
-    pub fn from_str<F: Fn(char) -> T>(input: &str, closure: F) -> Self {
-        let height = input.lines().count();
-
-        let width = input.lines().next().unwrap().len();
-
-        let items = input
-            .chars()
-            .filter(|x| x != &'\n')
-            .map(closure)
-            .collect::<Vec<T>>();
-
-        Grid {
-            width,
-            height,
-            items,
-        }
-    }
-
-    pub fn get(&self, point: &Point) -> &T {
-        match self.items.get(self.width * point.y + point.x) {
-            Some(x) => x,
-            None => panic!("({},{}) OOB for Grid", point.x, point.y),
-        }
-    }
-
-    pub fn find(&self, item: T) -> Option<Point> {
-        let result = self.items.iter().enumerate().find(|(_, x)| x == &&item);
-
-        result.map(|(idx, _)| {
-            Point::new(
-                idx % self.width, // x
-                idx / self.width, // y
-            )
-        })
-    }
-
-    pub fn find_all(&self, item: T) -> Vec<Point> {
-        self.items
-            .iter()
-            .enumerate()
-            .filter(|(_, x)| x == &&item)
-            .map(|(idx, _)| Point::new(idx % self.width, idx / self.width))
-            .collect()
-    }
-}
-
-impl<T: Display> Display for Grid<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (idx, item) in self.items.iter().enumerate() {
-            if idx % self.width == 0 {
-                writeln!(f).unwrap();
-            }
-            write!(f, "{:2} ", item).unwrap();
-        }
-        Ok(())
-    }
-}
-
 fn valid_elevation(src: &usize, dst: &usize) -> bool {
     if src >= dst || dst - src == 1 {
         return true;
@@ -90,93 +12,65 @@ fn valid_elevation(src: &usize, dst: &usize) -> bool {
     false
 }
 
-fn adjacent(map: &Grid<usize>, cur: &Point) -> Vec<Point> {
-    let mut adjacent = Vec::new();
-    let elevation = map.get(cur);
-
-    if cur.x > 0 {
-        let left = Point::new(cur.x - 1, cur.y);
-        if valid_elevation(elevation, map.get(&left)) {
-            adjacent.push(left);
-        }
-    }
-
-    if cur.y > 0 {
-        let top = Point::new(cur.x, cur.y - 1);
-        if valid_elevation(elevation, map.get(&top)) {
-            adjacent.push(top);
-        }
-    }
-
-    if cur.y < map.height - 1 {
-        let bottom = Point::new(cur.x, cur.y + 1);
-        if valid_elevation(elevation, map.get(&bottom)) {
-            adjacent.push(bottom);
-        }
-    }
+fn adjacent(map: &Grid<usize>, cur: &Point<usize>) -> Vec<Point<usize>> {
+    let elevation = &map[cur];
+    map.neighbours(cur)
+        .filter(|x| valid_elevation(elevation, &map[x]))
+        .collect()
+}
 
-    if cur.x < map.width - 1 {
-        let right = Point::new(cur.x + 1, cur.y);
-        if valid_elevation(elevation, map.get(&right)) {
-            adjacent.push(right);
-        }
-    }
-    adjacent
+/// Parses the heightmap, with the elevation `start` gets.
+fn parse_map(input: &str, start: usize) -> Result<Grid<usize>, AocError> {
+    Grid::from_str(input, |c| match c {
+        'S' => Some(start),
+        'E' => Some(END),
+        'a'..='z' => Some((c as usize) - ('a' as usize) + 1),
+        _ => None,
+    })
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let map_func = |c: char| -> usize {
-        match c {
-            'S' => START,
-            'E' => END,
-            x => (x as usize) - ('a' as usize) + 1,
-        }
-    };
+fn find(map: &Grid<usize>, item: usize) -> Result<Point<usize>, AocError> {
+    map.find(&item)
+        .ok_or_else(|| AocError::new(format!("No {}", if item == END { 'E' } else { 'S' })))
+}
 
-    let map = Grid::<usize>::from_str(input, map_func);
-    let end = map.find(END).unwrap();
-    let start = map.find(START).unwrap();
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let map = parse_map(input, START)?;
+    let end = find(&map, END)?;
+    let start = find(&map, START)?;
 
-    let mut visited: HashSet<Point> = HashSet::from([start.clone()]);
-    let mut queue: VecDeque<Point> = VecDeque::from([start.clone()]);
-    let mut depths: HashMap<Point, usize> = HashMap::from([(start, 0)]);
+    let mut visited: HashSet<Point<usize>> = HashSet::from([start]);
+    let mut queue: VecDeque<Point<usize>> = VecDeque::from([start]);
+    let mut depths: HashMap<Point<usize>, usize> = HashMap::from([(start, 0)]);
 
     while !queue.is_empty() {
         let p = queue.pop_front().unwrap();
         let depth = *depths.get(&p).unwrap();
         if p == end {
-            return Some(*depths.get(&end).unwrap() as u32);
+            return Ok(*depths.get(&end).unwrap() as u32);
         }
 
         for edge in adjacent(&map, &p) {
             if !visited.contains(&edge) {
-                visited.insert(edge.clone());
-                depths.insert(edge.clone(), depth + 1);
+                visited.insert(edge);
+                depths.insert(edge, depth + 1);
                 queue.push_back(edge);
             }
         }
     }
-    None
+    Err(AocError::new("No Path"))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let map_func = |c: char| -> usize {
-        match c {
-            'S' => 1,
-            'E' => END,
-            x => (x as usize) - ('a' as usize) + 1,
-        }
-    };
-
-    let map = Grid::<usize>::from_str(input, map_func);
-    let end = map.find(END).unwrap();
-    let start_points = map.find_all(1);
-    let mut shortest_path: usize = map.height * map.width + 1;
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let map = parse_map(input, 1)?;
+    let end = find(&map, END)?;
+    let start_points = map.find_all(&1);
+    let mut shortest_path: usize = map.height() * map.width() + 1;
 
     for start in start_points {
-        let mut visited: HashSet<Point> = HashSet::from([start.clone()]);
-        let mut queue: VecDeque<Point> = VecDeque::from([start.clone()]);
-        let mut depths: HashMap<Point, usize> = HashMap::from([(start.clone(), 0)]);
+        let mut visited: HashSet<Point<usize>> = HashSet::from([start]);
+        let mut queue: VecDeque<Point<usize>> = VecDeque::from([start]);
+        let mut depths: HashMap<Point<usize>, usize> = HashMap::from([(start, 0)]);
 
         while !queue.is_empty() {
             let p = queue.pop_front().unwrap();
@@ -190,18 +84,23 @@ pub fn part_two(input: &str) -> Option<u32> {
 
             for edge in adjacent(&map, &p) {
                 if !visited.contains(&edge) {
-                    visited.insert(edge.clone());
-                    depths.insert(edge.clone(), depth + 1);
+                    visited.insert(edge);
+                    depths.insert(edge, depth + 1);
                     queue.push_back(edge);
                 }
             }
         }
     }
-    Some(shortest_path as _)
+    if shortest_path > map.height() * map.width() {
+        return Err(AocError::new("No Path"));
+    }
+    Ok(shortest_path as _)
 }
 
+advent_of_code::solution!(12, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
+    let input = &advent_of_code::read_input(12);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -209,16 +108,55 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fmt::{self, Display};
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_one(&input), Some(31));
+        assert_eq!(part_one(&input), Ok(31));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_two(&input), Some(29));
+        assert_eq!(part_two(&input), Ok(29));
+    }
+
+    /// The heightmap, printed back with the letters the puzzle uses.
+    struct Heightmap(Grid<usize>);
+
+    impl Display for Heightmap {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let rows: Vec<String> = self
+                .0
+                .rows()
+                .map(|row| {
+                    row.iter()
+                        .map(|x| match *x {
+                            START => 'S',
+                            END => 'E',
+                            x => (b'a' + x as u8 - 1) as char,
+                        })
+                        .collect()
+                })
+                .collect();
+            write!(f, "{}", rows.join("\n"))
+        }
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(12, 1);
+        advent_of_code::fuzz::assert_round_trip(&[input.trim_end()], |x| {
+            parse_map(x, START).map(Heightmap)
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(12, seed),
+            part_two,
+        );
     }
 }
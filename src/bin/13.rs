@@ -1,5 +1,7 @@
+use advent_of_code::AocError;
 use itertools::Itertools;
 use std::cmp::Ordering;
+use std::fmt::{self, Display};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum Packet {
@@ -12,35 +14,52 @@ impl Packet {
         Packet::Packet(vec![Packet::Integer(int)])
     }
 
-    pub fn from_str(input: &str) -> Self {
-        if !input.starts_with('[') {
-            return Packet::Integer(input.parse::<u32>().unwrap());
-        }
-        if input.len() == 2 {
-            return Packet::Packet(vec![]);
+    pub fn from_str(input: &str) -> Result<Self, String> {
+        let inner = match input.strip_prefix('[') {
+            Some(rest) => rest
+                .strip_suffix(']')
+                .ok_or_else(|| format!("Unclosed Packet {}", input))?,
+            None => {
+                return input
+                    .parse::<u32>()
+                    .map(Packet::Integer)
+                    .map_err(|_| format!("Bad Integer {}", input))
+            }
+        };
+        if inner.is_empty() {
+            return Ok(Packet::Packet(vec![]));
         }
 
-        let mut start_idx = 1;
-        let mut idx = 1;
+        let mut start_idx = 0;
         let mut depth = 0;
         let mut items: Vec<Packet> = vec![];
 
-        while idx < (input.len() - 1) {
-            match input.chars().nth(idx).unwrap() {
-                ',' => {
-                    if depth == 0 {
-                        items.push(Packet::from_str(&input[start_idx..idx]));
-                        start_idx = idx + 1;
-                    }
+        for (idx, c) in inner.bytes().enumerate() {
+            match c {
+                b',' if depth == 0 => {
+                    items.push(Packet::from_str(&inner[start_idx..idx])?);
+                    start_idx = idx + 1;
                 }
-                '[' => depth += 1,
-                ']' => depth -= 1,
+                b'[' => depth += 1,
+                b']' if depth == 0 => return Err(format!("Unopened Bracket {}", input)),
+                b']' => depth -= 1,
                 _ => (),
             }
-            idx += 1;
         }
-        items.push(Packet::from_str(&input[start_idx..idx]));
-        Packet::Packet(items)
+        if depth != 0 {
+            return Err(format!("Unclosed Packet {}", input));
+        }
+        items.push(Packet::from_str(&inner[start_idx..])?);
+        Ok(Packet::Packet(items))
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(x) => write!(f, "{}", x),
+            Packet::Packet(items) => write!(f, "[{}]", items.iter().join(",")),
+        }
     }
 }
 
@@ -77,46 +96,45 @@ impl Ord for Packet {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .split("\n\n")
-            .enumerate()
-            .map(|(idx, x)| {
-                let mut iter = x.lines();
-                let p1 = Packet::from_str(iter.next().unwrap());
-                let p2 = Packet::from_str(iter.next().unwrap());
-                (idx + 1, p1 <= p2)
-            })
-            .filter(|(_, is_valid)| *is_valid)
-            .map(|(idx, _)| idx as u32)
-            .sum(),
-    )
+fn parse_packets(input: &str) -> Result<Vec<Packet>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| Packet::from_str(line).map_err(|e| AocError::at_line(idx + 1, e)))
+        .collect()
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let decoder: &str = "[[2]]\n[[6]]";
-    let mut input_with_decoder = input.to_owned();
-    input_with_decoder.push_str(decoder);
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let packets = parse_packets(input)?;
+    if packets.len() % 2 != 0 {
+        return Err(AocError::new("Packet Without Pair"));
+    }
 
-    let packets = input_with_decoder
-        .lines()
-        .filter(|x| !x.is_empty())
-        .map(Packet::from_str)
-        .sorted()
-        .collect::<Vec<Packet>>();
-
-    let key1 = Packet::from_str(decoder.lines().next().unwrap());
-    let key2 = Packet::from_str(decoder.lines().last().unwrap());
-
-    Some(
-        ((packets.iter().position(|x| x == &key1).unwrap() + 1)
-            * (packets.iter().position(|x| x == &key2).unwrap() + 1)) as u32,
-    )
+    Ok(packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] <= pair[1])
+        .map(|(idx, _)| (idx + 1) as u32)
+        .sum())
 }
 
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let key1 = Packet::Packet(vec![Packet::from_int(2)]);
+    let key2 = Packet::Packet(vec![Packet::from_int(6)]);
+
+    let mut packets = parse_packets(input)?;
+    packets.extend([key1.clone(), key2.clone()]);
+    packets.sort();
+
+    Ok(((packets.iter().position(|x| x == &key1).unwrap() + 1)
+        * (packets.iter().position(|x| x == &key2).unwrap() + 1)) as u32)
+}
+
+advent_of_code::solution!(13, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
+    let input = &advent_of_code::read_input(13);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -128,12 +146,27 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        assert_eq!(part_one(&input), Ok(13));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        assert_eq!(part_two(&input), Ok(140));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(13, 1);
+        let lines: Vec<&str> = input.lines().filter(|x| !x.is_empty()).collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, Packet::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(13, seed),
+            part_two,
+        );
     }
 }
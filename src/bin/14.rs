@@ -1,153 +1,124 @@
-use std::cmp::PartialEq;
+use advent_of_code::error::parse_lines;
+use advent_of_code::helpers::{Direction, Grid, Point};
+use advent_of_code::AocError;
 use std::collections::HashSet;
-use std::fmt::Debug;
+use std::ops::RangeInclusive;
 
 use itertools::Itertools;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-struct Point {
-    x: usize,
-    y: usize,
+const SOURCE: Point<usize> = Point { x: 500, y: 0 };
+/// Far deeper than the puzzle's caves, so a mangled coordinate fails instead of filling a cave for minutes.
+const MAX_DEPTH: usize = 1_000;
+
+/// The points from `from` to `to`, both included, along a row or a column, leaving out those outside of `reach`.
+fn line(
+    from: &Point<usize>,
+    to: &Point<usize>,
+    reach: &RangeInclusive<usize>,
+) -> impl Iterator<Item = Point<usize>> {
+    let xs = from.x.min(to.x).max(*reach.start())..=from.x.max(to.x).min(*reach.end());
+    let ys = from.y.min(to.y)..=from.y.max(to.y);
+    xs.flat_map(move |x| ys.clone().map(move |y| Point::new(x, y)))
 }
 
-impl Point {
-    pub fn from_str(input: &str) -> Self {
-        let (x, y) = input
-            .split(',')
-            .map(|z| z.parse::<usize>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        Point { x, y }
+fn parse_path(line: &str) -> Result<Vec<Point<usize>>, String> {
+    let path: Vec<Point<usize>> = line
+        .split(" -> ")
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    match path
+        .iter()
+        .tuple_windows()
+        .find(|(p1, p2)| p1.x != p2.x && p1.y != p2.y)
+    {
+        Some((p1, p2)) => Err(format!("Diagonal Rocks {} -> {}", p1, p2)),
+        None => Ok(path),
     }
+}
 
-    pub fn line(&self, other: &Self) -> Vec<Point> {
-        let mut points: Vec<Point> = Vec::from([other.clone()]);
+fn parse_rocks(input: &str) -> Result<HashSet<Point<usize>>, AocError> {
+    let paths = parse_lines(input, parse_path)?;
+    let depth = paths.iter().flatten().map(|p| p.y).max().unwrap_or(0);
+    if depth > MAX_DEPTH {
+        return Err(AocError::new(format!("Cave Deeper Than {}", MAX_DEPTH)));
+    }
+    // sand moves at most one step sideways per step down, so it never reaches rocks further out.
+    let reach = SOURCE.x.saturating_sub(depth + 2)..=SOURCE.x + depth + 2;
 
-        for x in (self.x..other.x).chain((other.x + 1)..=self.x) {
-            points.push(Point { x, y: self.y })
-        }
-        for y in (self.y..other.y).chain((other.y + 1)..=self.y) {
-            points.push(Point { x: self.x, y })
+    let mut map: HashSet<Point<usize>> = HashSet::new();
+    for path in paths {
+        for (p1, p2) in path.iter().tuple_windows() {
+            map.extend(line(p1, p2, &reach));
         }
-        points
     }
+    if map.is_empty() {
+        return Err(AocError::new("No Rocks"));
+    }
+    Ok(map)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut map: HashSet<Point> = HashSet::new();
-
-    for line in input.lines() {
-        for (p1, p2) in line.split(" -> ").map(Point::from_str).tuple_windows() {
-            for point in p1.line(&p2) {
-                map.insert(point);
-            }
-        }
-    }
+/// Where sand at `sand` tries to go next: down, down left, then down right.
+/// A step that would leave the `usize` range counts as blocked.
+fn falls(sand: &Point<usize>) -> impl Iterator<Item = Point<usize>> {
+    let below = sand.step(Direction::Down);
+    [
+        below,
+        below.and_then(|x| x.step(Direction::Left)),
+        below.and_then(|x| x.step(Direction::Right)),
+    ]
+    .into_iter()
+    .flatten()
+}
 
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let mut map = parse_rocks(input)?;
     let max_depth = map.iter().map(|p| p.y).max().unwrap();
-
     let rock_count = map.len();
 
-    let mut infinite: bool = false;
-
-    while !infinite {
-        let mut sand = Point { x: 500, y: 0 };
-        map.insert(sand.clone());
-        loop {
-            if sand.y > max_depth {
-                infinite = true;
-                break;
-            }
-            if !map.contains(&Point {
-                x: sand.x,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else if !map.contains(&Point {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.x -= 1;
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else if !map.contains(&Point {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.x += 1;
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else {
-                break;
+    loop {
+        let mut sand = SOURCE;
+        while let Some(next) = falls(&sand).find(|x| !map.contains(x)) {
+            if next.y > max_depth {
+                return Ok((map.len() - rock_count) as u32);
             }
+            sand = next;
         }
+        map.insert(sand);
     }
-
-    Some((map.len() - (rock_count + 1)) as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map: HashSet<Point> = HashSet::new();
-
-    for line in input.lines() {
-        for (p1, p2) in line.split(" -> ").map(Point::from_str).tuple_windows() {
-            for point in p1.line(&p2) {
-                map.insert(point);
-            }
-        }
-    }
-
-    let max_depth = map.iter().map(|p| p.y).max().unwrap() + 2;
-
-    let rock_count = map.len();
-
-    loop {
-        let mut sand = Point { x: 500, y: 0 };
-        if map.contains(&sand) {
-            break;
-        }
-        map.insert(sand.clone());
-        loop {
-            if sand.y == (max_depth - 1) {
-                break;
-            }
-            if !map.contains(&Point {
-                x: sand.x,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else if !map.contains(&Point {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.x -= 1;
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else if !map.contains(&Point {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            }) {
-                map.remove(&sand);
-                sand.x += 1;
-                sand.y += 1;
-                map.insert(sand.clone());
-            } else {
-                break;
-            }
-        }
+/// With a floor, sand comes to rest on every point it can fall to from the source,
+/// so the cave is filled a row at a time instead of a unit of sand at a time.
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let rocks = parse_rocks(input)?;
+    let floor = rocks.iter().map(|p| p.y).max().unwrap() + 2;
+
+    // sand spreads at most one step sideways per row, so `left` is the leftmost point it reaches.
+    let left = SOURCE.x.saturating_sub(floor);
+    let cave = Grid::from_fn(SOURCE.x + floor + 1 - left, floor, |p| {
+        rocks.contains(&Point::new(p.x + left, p.y))
+    });
+
+    let mut row = vec![false; cave.width()];
+    row[SOURCE.x - left] = !cave[&Point::new(SOURCE.x - left, 0)];
+    let mut sand = row.iter().filter(|x| **x).count();
+
+    for y in 1..cave.height() {
+        row = (0..cave.width())
+            .map(|x| {
+                let above = &row[x.saturating_sub(1)..(x + 2).min(row.len())];
+                !cave[&Point::new(x, y)] && above.contains(&true)
+            })
+            .collect();
+        sand += row.iter().filter(|x| **x).count();
     }
-    Some((map.len() - rock_count) as u32)
+    Ok(sand as u32)
 }
 
+advent_of_code::solution!(14, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
+    let input = &advent_of_code::read_input(14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -159,12 +130,29 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_one(&input), Some(24));
+        assert_eq!(part_one(&input), Ok(24));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_two(&input), Some(93));
+        assert_eq!(part_two(&input), Ok(93));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(14, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, |x| {
+            parse_path(x).map(|path| path.iter().join(" -> "))
+        });
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(14, seed),
+            part_two,
+        );
     }
 }
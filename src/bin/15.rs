@@ -1,3 +1,6 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::helpers::Point;
+use advent_of_code::AocError;
 use itertools::Itertools;
 use nom::{
     bytes::complete::{is_not, tag},
@@ -5,35 +8,35 @@ use nom::{
     IResult,
 };
 use std::cmp;
-
-#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    pub fn mdist(&self, other: &Self) -> i32 {
-        ((self.x as i32 - other.x as i32).abs()) + ((self.y as i32 - other.y as i32).abs())
-    }
-}
+use std::fmt::{self, Display};
 
 #[derive(Debug, PartialEq)]
 struct Sensor {
-    location: Point,
-    beacon: Point,
+    location: Point<i32>,
+    beacon: Point<i32>,
     mdist: i32,
 }
 
 impl Sensor {
-    pub fn from_str(input: &str) -> Self {
+    pub fn from_str(input: &str) -> Result<Self, String> {
         match parse_sensor(input) {
-            Ok(s) => s.1,
-            Err(e) => panic!("{}", e),
+            Ok(("", sensor)) => Ok(sensor),
+            Ok((rest, _)) => Err(format!("Unexpected {}", rest)),
+            Err(e) => Err(e.to_string()),
         }
     }
 }
 
+impl Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            self.location.x, self.location.y, self.beacon.x, self.beacon.y
+        )
+    }
+}
+
 fn parse_i32(input: &str) -> IResult<&str, i32> {
     map_res(is_not(",:"), |s: &str| s.parse::<i32>())(input)
 }
@@ -55,7 +58,20 @@ fn parse_sensor(input: &str) -> IResult<&str, Sensor> {
         y: beacon_y,
     };
 
-    let mdist = location.mdist(&beacon);
+    // the solvers walk one step past the covered area, which has to fit in an i32 too.
+    let reach = (loc_x as i64 - beacon_x as i64).abs() + (loc_y as i64 - beacon_y as i64).abs() + 1;
+    let in_range = |x: i32| {
+        (i32::MIN as i64..=i32::MAX as i64).contains(&(x as i64 - reach))
+            && (i32::MIN as i64..=i32::MAX as i64).contains(&(x as i64 + reach))
+    };
+    if !in_range(loc_x) || !in_range(loc_y) {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::TooLarge,
+        )));
+    }
+
+    let mdist = location.manhattan(&beacon);
     Ok((
         input,
         Sensor {
@@ -66,8 +82,11 @@ fn parse_sensor(input: &str) -> IResult<&str, Sensor> {
     ))
 }
 
-fn part_one_with_y(input: &str, y: i32) -> Option<u32> {
-    let sensors: Vec<Sensor> = input.lines().map(Sensor::from_str).collect();
+fn part_one_with_y(input: &str, y: i32) -> Result<u32, AocError> {
+    let sensors: Vec<Sensor> = parse_lines(input, Sensor::from_str)?;
+    if sensors.is_empty() {
+        return Err(AocError::new("No Sensors"));
+    }
 
     let min_x = sensors
         .iter()
@@ -85,24 +104,24 @@ fn part_one_with_y(input: &str, y: i32) -> Option<u32> {
         .filter(|x| {
             sensors
                 .iter()
-                .any(|s| s.location.mdist(&Point { x: *x, y }) <= s.mdist)
+                .any(|s| s.location.manhattan(&Point { x: *x, y }) <= s.mdist)
         })
         .count();
 
     let beacons = sensors
         .iter()
-        .map(|s| s.beacon.clone())
+        .map(|s| s.beacon)
         .filter(|b| b.y == y)
         .unique()
         .count();
 
-    Some((potential - beacons) as _)
+    Ok((potential - beacons) as _)
 }
 
-fn part_two_with_max(input: &str, max: u32) -> Option<u64> {
-    let sensors: Vec<Sensor> = input.lines().map(Sensor::from_str).collect();
+fn part_two_with_max(input: &str, max: u32) -> Result<u64, AocError> {
+    let sensors: Vec<Sensor> = parse_lines(input, Sensor::from_str)?;
 
-    let mut circles: Vec<Point> = Vec::new();
+    let mut circles: Vec<Point<i32>> = Vec::new();
 
     for sensor in sensors.iter() {
         let min_y = cmp::max(sensor.location.y - sensor.mdist - 1, 0);
@@ -125,28 +144,30 @@ fn part_two_with_max(input: &str, max: u32) -> Option<u64> {
     for point in circles.iter() {
         valid = true;
         for sensor in sensors.iter() {
-            if sensor.location.mdist(point) <= sensor.mdist {
+            if sensor.location.manhattan(point) <= sensor.mdist {
                 valid = false;
                 break;
             }
         }
         if valid {
-            return Some((point.x as u64 * 4000000) + point.y as u64);
+            return Ok((point.x as u64 * 4000000) + point.y as u64);
         }
     }
-    None
+    Err(AocError::new("No Uncovered Position"))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, AocError> {
     part_one_with_y(input, 2000000)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str) -> Result<u64, AocError> {
     part_two_with_max(input, 4000000)
 }
 
+advent_of_code::solution!(15, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
+    let input = &advent_of_code::read_input(15);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -159,27 +180,42 @@ mod tests {
     fn test_parser() {
         let location = Point { x: 2, y: 18 };
         let beacon = Point { x: -2, y: 15 };
-        let mdist = location.mdist(&beacon);
+        let mdist = location.manhattan(&beacon);
         let input_str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
         assert_eq!(
             Sensor::from_str(input_str),
-            Sensor {
+            Ok(Sensor {
                 location,
                 beacon,
                 mdist
-            }
+            })
         );
     }
 
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 15);
-        assert_eq!(part_one_with_y(&input, 10), Some(26));
+        assert_eq!(part_one_with_y(&input, 10), Ok(26));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 15);
-        assert_eq!(part_two_with_max(&input, 20), Some(56000011));
+        assert_eq!(part_two_with_max(&input, 20), Ok(56000011));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(15, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, Sensor::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(15, seed),
+            part_two,
+        );
     }
 }
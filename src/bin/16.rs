@@ -1,5 +1,8 @@
+use advent_of_code::error::parse_lines;
+use advent_of_code::AocError;
 use std::cmp;
 use std::collections::HashMap;
+use std::fmt::{self, Display};
 
 use nom::{
     branch::alt,
@@ -10,7 +13,40 @@ use nom::{
     IResult,
 };
 
-fn parse_valve(input: &str) -> IResult<&str, (String, u32, Vec<String>)> {
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl Valve {
+    pub fn from_str(input: &str) -> Result<Self, String> {
+        match parse_valve(input) {
+            Ok(("", valve)) => Ok(valve),
+            Ok((rest, _)) => Err(format!("Unexpected {}", rest)),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+impl Display for Valve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tunnels = match self.tunnels.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        write!(
+            f,
+            "Valve {} has flow rate={}; {} {}",
+            self.name,
+            self.flow_rate,
+            tunnels,
+            self.tunnels.join(", ")
+        )
+    }
+}
+
+fn parse_valve(input: &str) -> IResult<&str, Valve> {
     let (input, _) = tag("Valve ")(input)?;
     let (input, name) = is_not(" ")(input)?;
     let (input, _) = tag(" has flow rate=")(input)?;
@@ -19,11 +55,21 @@ fn parse_valve(input: &str) -> IResult<&str, (String, u32, Vec<String>)> {
     let (input, _) = alt((tag("lead "), tag("leads ")))(input)?;
     let (input, _) = alt((tag("to valve "), tag("to valves ")))(input)?;
     let (input, conn_str) = separated_list1(tag(", "), alpha1)(input)?;
-    let connections = conn_str.iter().map(|x| String::from(*x)).collect();
-
-    Ok((input, (name.to_owned(), flow_rate, connections)))
+    let tunnels = conn_str.iter().map(|x| String::from(*x)).collect();
+
+    Ok((
+        input,
+        Valve {
+            name: name.to_owned(),
+            flow_rate,
+            tunnels,
+        },
+    ))
 }
 
+const START: &str = "AA";
+const TIME: u32 = 30;
+
 struct ValveGraph {
     flow_rates: HashMap<String, u32>,
     state_masks: HashMap<String, u32>,
@@ -31,22 +77,39 @@ struct ValveGraph {
 }
 
 impl ValveGraph {
-    pub fn from_str(input: &str) -> Self {
+    pub fn from_str(input: &str) -> Result<Self, AocError> {
         let mut names: Vec<String> = vec![];
         let mut graph: HashMap<String, Vec<String>> = HashMap::new();
         let mut flow_rates: HashMap<String, u32> = HashMap::new();
         let mut state_masks: HashMap<String, u32> = HashMap::new();
 
-        for line in input.lines() {
-            let (_, (name, flow_rate, tunnels)) = parse_valve(line).unwrap();
-
-            names.push(name.clone());
-            graph.insert(name.clone(), tunnels);
-            if flow_rate > 0 {
-                flow_rates.insert(name.clone(), flow_rate);
-                state_masks.insert(name, 1 << state_masks.len());
+        for (idx, valve) in parse_lines(input, Valve::from_str)?.into_iter().enumerate() {
+            if graph.contains_key(&valve.name) {
+                return Err(AocError::at_line(
+                    idx + 1,
+                    format!("Duplicate Valve {}", valve.name),
+                ));
             }
+            names.push(valve.name.clone());
+            graph.insert(valve.name.clone(), valve.tunnels);
+            if valve.flow_rate > 0 {
+                // the open valves are a bitmask in a u32.
+                if state_masks.len() == u32::BITS as usize {
+                    return Err(AocError::at_line(idx + 1, "Too Many Working Valves"));
+                }
+                flow_rates.insert(valve.name.clone(), valve.flow_rate);
+                state_masks.insert(valve.name, 1 << state_masks.len());
+            }
+        }
+        if !graph.contains_key(START) {
+            return Err(AocError::new(format!("No Valve {}", START)));
         }
+        // every valve open for the whole time is the most pressure either part can release.
+        flow_rates
+            .values()
+            .try_fold(0_u32, |total, x| total.checked_add(*x))
+            .and_then(|x| x.checked_mul(TIME))
+            .ok_or_else(|| AocError::new("Flow Rates Too Large"))?;
 
         // Floyd-Warshall
         let mut distances: HashMap<String, HashMap<String, u32>> = names
@@ -77,11 +140,11 @@ impl ValveGraph {
                 }
             }
         }
-        ValveGraph {
+        Ok(ValveGraph {
             flow_rates,
             state_masks,
             distances,
-        }
+        })
     }
 
     fn simulate(&self, start_node: &str, start_time: u32) -> HashMap<u32, u32> {
@@ -121,19 +184,13 @@ impl ValveGraph {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        *ValveGraph::from_str(input)
-            .simulate("AA", 30)
-            .iter()
-            .map(|(_, v)| v)
-            .max()
-            .unwrap(),
-    )
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let pressure_map = ValveGraph::from_str(input)?.simulate(START, TIME);
+    Ok(*pressure_map.values().max().unwrap())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let pressure_map = ValveGraph::from_str(input).simulate("AA", 26);
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let pressure_map = ValveGraph::from_str(input)?.simulate(START, TIME - 4);
     let mut max_pressure = 0;
 
     for (state, value) in pressure_map.iter() {
@@ -143,11 +200,13 @@ pub fn part_two(input: &str) -> Option<u32> {
             }
         }
     }
-    Some(max_pressure)
+    Ok(max_pressure)
 }
 
+advent_of_code::solution!(16, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
+    let input = &advent_of_code::read_input(16);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -159,12 +218,27 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_one(&input), Some(1651));
+        assert_eq!(part_one(&input), Ok(1651));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_two(&input), Some(1707));
+        assert_eq!(part_two(&input), Ok(1707));
+    }
+
+    #[test]
+    fn test_parser_round_trip() {
+        let input = advent_of_code::fuzz::well_formed(16, 1);
+        let lines: Vec<&str> = input.lines().collect();
+        advent_of_code::fuzz::assert_round_trip(&lines, Valve::from_str);
+    }
+
+    #[test]
+    fn test_parser_fuzz() {
+        advent_of_code::fuzz::assert_no_panic(
+            |seed| advent_of_code::fuzz::well_formed(16, seed),
+            part_two,
+        );
     }
 }
@@ -0,0 +1,359 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Verdict;
+use advent_of_code::input::year_file_path;
+use advent_of_code::layout::{self, DEFAULT_YEAR};
+use advent_of_code::solution::{self, DayResults, Status};
+use advent_of_code::{days, history, vault};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::BTreeMap;
+use std::process::{self, Command, Output};
+use std::time::Duration;
+use std::{env, panic};
+
+const DAYS: u8 = 25;
+const HELP: &str = "↑/↓ select · r run · t test · p puzzle · d download · q quit";
+
+struct Args {
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+    })
+}
+
+/// What the dashboard knows about a day.
+#[derive(Default)]
+struct DayState {
+    /// Results of running the day from the dashboard.
+    results: Option<DayResults>,
+    /// Whether `cargo test` passed, once run.
+    examples: Option<bool>,
+    /// Per-part times of the last `cargo all` run in the timing history.
+    recorded: BTreeMap<u8, Duration>,
+}
+
+struct App {
+    year: u16,
+    days: Vec<DayState>,
+    table: TableState,
+    message: String,
+}
+
+fn has_input(year: u16, day: u8) -> bool {
+    let path = year_file_path(year, "inputs", day);
+    path.exists() || vault::blob_path(&path).exists()
+}
+
+fn part_cell(results: &Option<DayResults>, part: usize) -> String {
+    let result = match results {
+        Some(results) => &results.parts[part],
+        None => return String::new(),
+    };
+    match (result.status, result.verdict) {
+        (Status::Solved, Verdict::Correct) => String::from("✅ verified"),
+        (Status::Solved, Verdict::Wrong) => String::from("❌ wrong"),
+        (Status::Solved, Verdict::Unknown) => String::from("⭐ solved"),
+        (status, _) => status.as_str().replace('_', " "),
+    }
+}
+
+fn time_cell(state: &DayState) -> String {
+    match &state.results {
+        Some(results) => {
+            let total: Duration = results
+                .iter()
+                .filter(|x| x.status == Status::Solved)
+                .map(|x| x.elapsed)
+                .sum();
+            format!("{:.2?}", total)
+        }
+        None if !state.recorded.is_empty() => {
+            format!(
+                "{:.2?} (last run)",
+                state.recorded.values().sum::<Duration>()
+            )
+        }
+        None => String::new(),
+    }
+}
+
+/// The cells of a day's row: day, solution, input, examples, part 1, part 2, time.
+fn cells(year: u16, day: u8, state: &DayState) -> [String; 7] {
+    let yes_no = |x: bool| String::from(if x { "✓" } else { "·" });
+    [
+        format!("{:>2}", day),
+        yes_no(days::get(year, day).is_some()),
+        yes_no(has_input(year, day)),
+        match state.examples {
+            Some(true) => String::from("passing"),
+            Some(false) => String::from("failing"),
+            None => String::new(),
+        },
+        part_cell(&state.results, 0),
+        part_cell(&state.results, 1),
+        time_cell(state),
+    ]
+}
+
+fn cargo(args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .args(args)
+        .output()
+}
+
+/// The last line a command printed, to show in the status bar: its result, or else its error.
+fn last_line(output: &Output) -> String {
+    let printed = if output.status.success() {
+        &output.stdout
+    } else {
+        &output.stderr
+    };
+    String::from_utf8_lossy(printed)
+        .lines()
+        .rev()
+        .find(|x| !x.trim().is_empty())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+impl App {
+    fn new(year: u16) -> Self {
+        let mut days: Vec<DayState> = (1..=DAYS).map(|_| DayState::default()).collect();
+
+        let history = history::load().unwrap_or_default();
+        for entry in history::baseline(&history, year, history::LAST).unwrap_or_default() {
+            if let Some(state) = days.get_mut(entry.day as usize - 1) {
+                state.recorded.insert(entry.part, entry.elapsed);
+            }
+        }
+
+        App {
+            year,
+            days,
+            table: TableState::default().with_selected(0),
+            message: String::from(HELP),
+        }
+    }
+
+    fn day(&self) -> u8 {
+        self.table.selected().unwrap_or(0) as u8 + 1
+    }
+
+    fn state(&mut self) -> &mut DayState {
+        let day = self.day();
+        &mut self.days[day as usize - 1]
+    }
+
+    /// Runs the day in-process, like `cargo all` does.
+    fn run(&mut self) {
+        let (year, day) = (self.year, self.day());
+        let solution = match days::get(year, day) {
+            Some(solution) => solution,
+            None => {
+                self.message = format!("Day {} has no solution yet, scaffold it first.", day);
+                return;
+            }
+        };
+        let input = match advent_of_code::input::try_read_year_file(year, "inputs", day) {
+            Ok(input) => input,
+            Err(e) => {
+                self.message = e.to_string();
+                return;
+            }
+        };
+
+        // a panicking solver is reported in the table; its message would garble the screen.
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let results = solution::run_day(solution, &input);
+        panic::set_hook(hook);
+
+        self.message = format!("Ran day {}.", day);
+        self.state().results = Some(results);
+    }
+
+    fn test(&mut self) {
+        let bin = layout::bin_name(self.year, self.day());
+        match cargo(&["test", "--bin", &bin]) {
+            Ok(output) => {
+                self.message = last_line(&output);
+                self.state().examples = Some(output.status.success());
+            }
+            Err(e) => self.message = format!("Failed to run cargo test: {}", e),
+        }
+    }
+
+    fn download(&mut self) {
+        let (day, year) = (self.day().to_string(), self.year.to_string());
+        match cargo(&["run", "--bin", "download", "--", &day, "--year", &year]) {
+            Ok(output) => self.message = last_line(&output),
+            Err(e) => self.message = format!("Failed to run cargo download: {}", e),
+        }
+    }
+
+    /// Shows `puzzles/NN.md` in `$PAGER`, leaving the dashboard until it exits.
+    fn puzzle(&mut self, terminal: &mut DefaultTerminal) {
+        let path = layout::puzzle_path(self.year, self.day());
+        if !path.exists() {
+            self.message = format!(
+                "{} does not exist, fetch it with `cargo scaffold {} --puzzle`.",
+                path.display(),
+                self.day()
+            );
+            return;
+        }
+
+        let pager = env::var("PAGER").unwrap_or_else(|_| String::from("less"));
+        ratatui::restore();
+        let status = Command::new(&pager).arg(&path).status();
+        *terminal = ratatui::init();
+
+        if let Err(e) = status {
+            self.message = format!("Failed to run {}: {}", pager, e);
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, message_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(frame.area());
+
+        let header = Row::new([
+            "Day", "Solution", "Input", "Examples", "Part 1", "Part 2", "Time",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows = self
+            .days
+            .iter()
+            .enumerate()
+            .map(|(idx, state)| Row::new(cells(self.year, idx as u8 + 1, state)));
+        let widths = [
+            Constraint::Length(4),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Length(9),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Min(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(format!(" 🎄 Advent of Code {} ", self.year)))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+        frame.render_widget(
+            Paragraph::new(Line::from(self.message.as_str())).block(Block::bordered()),
+            message_area,
+        );
+    }
+
+    /// Shows `message` right away, as the following step blocks the dashboard.
+    fn busy(&mut self, terminal: &mut DefaultTerminal, message: String) -> std::io::Result<()> {
+        self.message = message;
+        terminal.draw(|frame| self.draw(frame))?;
+        Ok(())
+    }
+
+    fn main_loop(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                _ => continue,
+            };
+            let day = self.day();
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.table.select(Some(day.min(DAYS - 1) as usize));
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.table.select(Some(day.saturating_sub(2) as usize));
+                }
+                KeyCode::Char('r') | KeyCode::Enter => {
+                    self.busy(terminal, format!("Running day {}…", day))?;
+                    self.run();
+                }
+                KeyCode::Char('t') => {
+                    self.busy(terminal, format!("Testing day {}…", day))?;
+                    self.test();
+                }
+                KeyCode::Char('d') => {
+                    self.busy(terminal, format!("Downloading input for day {}…", day))?;
+                    self.download();
+                }
+                KeyCode::Char('p') => self.puzzle(terminal),
+                _ => self.message = String::from(HELP),
+            }
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut app = App::new(args.year);
+    let mut terminal = ratatui::init();
+    let result = app.main_loop(&mut terminal);
+    ratatui::restore();
+
+    if let Err(e) = result {
+        eprintln!("Dashboard failed: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::solution::PartResult;
+
+    #[test]
+    fn test_cells() {
+        let state = DayState {
+            results: Some(DayResults {
+                parse: None,
+                parts: [
+                    PartResult {
+                        status: Status::Solved,
+                        verdict: Verdict::Correct,
+                        elapsed: Duration::from_micros(1500),
+                        ..PartResult::not_solved(2022, 30, 1)
+                    },
+                    PartResult {
+                        status: Status::Panicked,
+                        ..PartResult::not_solved(2022, 30, 2)
+                    },
+                ],
+            }),
+            examples: Some(false),
+            recorded: BTreeMap::new(),
+        };
+
+        let cells = cells(2022, 30, &state);
+        assert_eq!(cells[3], "failing");
+        assert_eq!(cells[4], "✅ verified");
+        assert_eq!(cells[5], "panicked");
+        assert_eq!(cells[6], "1.50ms");
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::generate;
+use advent_of_code::layout::DEFAULT_YEAR;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// How many times as large as a real input, e.g. `--size 10`.
+    size: f64,
+    /// Picked from the clock and printed when not given, so a run can be repeated.
+    seed: Option<u64>,
+    /// Write here instead of stdout.
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        size: args.opt_value_from_str("--size")?.unwrap_or(1_f64),
+        seed: args.opt_value_from_str("--seed")?,
+        output: args.opt_value_from_str(["-o", "--output"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.size.is_nan() || args.size <= 0_f64 {
+        eprintln!("--size must be positive, got {}", args.size);
+        process::exit(1);
+    }
+
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_nanos() as u64);
+        eprintln!("Using --seed {}", seed);
+        seed
+    });
+
+    let input = match generate::generate(args.year, args.day, args.size, seed) {
+        Some(input) => input,
+        None => {
+            eprintln!(
+                "There is no generator for {} day {} yet, add one in src/generate.rs.",
+                args.year, args.day
+            );
+            process::exit(1);
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = fs::write(path, input) {
+                eprintln!("Failed to write {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", input),
+    }
+}
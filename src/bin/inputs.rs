@@ -0,0 +1,122 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::layout::{self, DEFAULT_YEAR};
+use advent_of_code::vault::{self, Vault};
+use std::path::{Path, PathBuf};
+use std::{fs, process};
+
+const USAGE: &str = "Usage: `cargo inputs vault` to encrypt src/inputs/*.txt, `cargo inputs unvault` to decrypt them. Both take `--year YYYY`.";
+
+enum Command {
+    /// Encrypts every `inputs/NN.txt` into `inputs/NN.txt.vault`.
+    Vault,
+    /// Writes every `inputs/NN.txt.vault` back to `inputs/NN.txt`.
+    Unvault,
+}
+
+struct Args {
+    command: Command,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = pico_args::Arguments::from_env();
+    let command = match args.subcommand().map_err(|e| e.to_string())?.as_deref() {
+        Some("vault") => Command::Vault,
+        Some("unvault") => Command::Unvault,
+        _ => return Err(String::from(USAGE)),
+    };
+    let year = args
+        .opt_value_from_str(["-y", "--year"])
+        .map_err(|e| e.to_string())?
+        .unwrap_or(DEFAULT_YEAR);
+    Ok(Args { command, year })
+}
+
+/// The files in `dir` ending in `suffix`, sorted.
+fn files_ending_in(dir: &Path, suffix: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|x| x.path()))
+        .filter(|path| path.to_string_lossy().ends_with(suffix))
+        .collect();
+    files.sort();
+    files
+}
+
+fn exit_with(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn vault_inputs(secret: &[u8], inputs_dir: &Path) {
+    let vault = Vault::new(secret).unwrap_or_else(|e| exit_with(e.to_string()));
+
+    for path in files_ending_in(inputs_dir, ".txt") {
+        let plaintext = fs::read(&path)
+            .unwrap_or_else(|e| exit_with(format!("Failed to read {}: {}", path.display(), e)));
+        if plaintext.is_empty() {
+            continue;
+        }
+
+        let blob_path = vault::blob_path(&path);
+        // re-encrypting changes every blob, so leave the ones that still match alone.
+        let unchanged = fs::read(&blob_path)
+            .ok()
+            .and_then(|blob| vault::decrypt(secret, &blob).ok())
+            .is_some_and(|x| x == plaintext);
+        if unchanged {
+            continue;
+        }
+
+        match fs::write(&blob_path, vault.encrypt(&plaintext)) {
+            Ok(_) => println!("Encrypted \"{}\"", blob_path.display()),
+            Err(e) => exit_with(format!("Failed to write {}: {}", blob_path.display(), e)),
+        }
+    }
+}
+
+fn unvault_inputs(secret: &[u8], inputs_dir: &Path) {
+    let suffix = format!(".txt.{}", vault::EXTENSION);
+
+    for blob_path in files_ending_in(inputs_dir, &suffix) {
+        let path = PathBuf::from(
+            blob_path
+                .to_string_lossy()
+                .trim_end_matches(&format!(".{}", vault::EXTENSION)),
+        );
+        let blob = fs::read(&blob_path).unwrap_or_else(|e| {
+            exit_with(format!("Failed to read {}: {}", blob_path.display(), e))
+        });
+        let plaintext = vault::decrypt(secret, &blob)
+            .unwrap_or_else(|e| exit_with(format!("{}: {}", blob_path.display(), e)));
+
+        match fs::write(&path, plaintext) {
+            Ok(_) => println!("Decrypted \"{}\"", path.display()),
+            Err(e) => exit_with(format!("Failed to write {}: {}", path.display(), e)),
+        }
+    }
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| exit_with(e));
+    let secret = vault::secret().unwrap_or_else(|| {
+        exit_with(format!(
+            "Need a key: set {} or put a passphrase in {}",
+            vault::KEY_ENV,
+            vault::KEY_FILE
+        ))
+    });
+    let inputs_dir = layout::year_dir(args.year).join("inputs");
+
+    match args.command {
+        Command::Vault => vault_inputs(&secret, &inputs_dir),
+        Command::Unvault => unvault_inputs(&secret, &inputs_dir),
+    }
+
+    println!("---");
+    println!("🎄 Done.");
+}
@@ -2,9 +2,14 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::client::Client;
+use advent_of_code::input::{crate_root, year_example_path, year_file_path};
+use advent_of_code::layout::{self, DEFAULT_YEAR};
+use advent_of_code::puzzle;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
@@ -16,8 +21,10 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
+advent_of_code::solution!(DAY, part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
+    let input = &advent_of_code::read_input(DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -40,33 +47,219 @@ mod tests {
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: u16,
+    /// Also fetch the puzzle description into `puzzles/NN.md`, its example if `examples/NN.txt` is empty
+    /// and the example answers into the generated tests.
+    puzzle: bool,
+    /// Named examples to create as `examples/NN-<variant>.txt`, for `read_example` and `example_tests!`.
+    variants: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        puzzle: args.contains("--puzzle"),
+        variants: args.values_from_str("--variant")?,
+        day: args.free_from_str()?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// Days of other years read their files through the year-aware helpers.
+fn module_contents(year: u16, day: u8) -> String {
+    let template = if year == DEFAULT_YEAR {
+        MODULE_TEMPLATE.to_string()
+    } else {
+        MODULE_TEMPLATE
+            .replace("solution!(DAY,", "solution!(YEAR, DAY,")
+            .replace("read_input(DAY)", "read_year_input(YEAR, DAY)")
+            .replace(
+                "read_file(\"examples\", DAY)",
+                "read_year_file(YEAR, \"examples\", DAY)",
+            )
+    };
+    template
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+}
+
+/// Bins outside of `src/bin` are not discovered by cargo, so other years are listed in `Cargo.toml`.
+fn register_bin(year: u16, day: u8) -> Result<(), std::io::Error> {
+    let manifest_path = crate_root().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let name = layout::bin_name(year, day);
+
+    if manifest.contains(&format!("name = \"{}\"", name)) {
+        return Ok(());
+    }
+
+    let mut file = OpenOptions::new().append(true).open(manifest_path)?;
+    write!(
+        file,
+        "\n[[bin]]\nname = \"{}\"\npath = \"src/{}/bin/{:02}.rs\"\n",
+        name, year, day
+    )
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+/// Writes the puzzle description as Markdown. Re-running once part one is solved adds part two.
+fn fetch_puzzle(year: u16, day: u8) -> String {
+    match Client::from_env().and_then(|client| client.puzzle(year, day)) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Failed to fetch puzzle: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn write_puzzle(year: u16, day: u8, page: &str) {
+    let puzzle_path = layout::puzzle_path(year, day);
+
+    let markdown = match puzzle::markdown(page) {
+        Some(markdown) => markdown,
+        None => {
+            eprintln!("Failed to find the puzzle description in the page");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let written = fs::create_dir_all(puzzle_path.parent().unwrap())
+        .and_then(|_| fs::write(&puzzle_path, markdown));
+    match written {
+        Ok(_) => {
+            let parts = match puzzle::articles(page).len() {
+                1 => "part one",
+                _ => "parts one and two",
+            };
+            println!(
+                "Wrote puzzle description ({}) to \"{}\"",
+                parts,
+                puzzle_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write puzzle file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn write_example(year: u16, day: u8, page: &str) {
+    let example_path = year_file_path(year, "examples", day);
+
+    match puzzle::write_example(page, &example_path) {
+        Ok(true) => println!("Wrote example to \"{}\"", example_path.display()),
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to write example file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Replaces the generated `None` assertion of `part` with the example answer.
+/// Non-numeric and large answers also change the part's return type, as long as it is still the generated one.
+fn fill_answer(module: &str, part: u8, answer: &str) -> String {
+    let name = if part == 1 { "part_one" } else { "part_two" };
+    let placeholder = format!("assert_eq!({}(&input), None);", name);
+    if !module.contains(&placeholder) {
+        return module.to_string();
+    }
+
+    let (return_type, expected) = if answer.parse::<u32>().is_ok() {
+        (None, answer.to_string())
+    } else if answer.parse::<u64>().is_ok() {
+        (Some("u64"), answer.to_string())
+    } else if answer.parse::<i64>().is_ok() {
+        (Some("i64"), answer.to_string())
+    } else {
+        (Some("String"), format!("String::from({:?})", answer))
+    };
+
+    let module = module.replace(
+        &placeholder,
+        &format!("assert_eq!({}(&input), Some({}));", name, expected),
+    );
+    match return_type {
+        Some(return_type) => module.replace(
+            &format!("pub fn {}(input: &str) -> Option<u32> {{", name),
+            &format!("pub fn {}(input: &str) -> Option<{}> {{", name, return_type),
+        ),
+        None => module,
+    }
+}
+
+fn write_answers(year: u16, day: u8, page: &str) {
+    let module_path = layout::bin_path(year, day);
+    let module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to read module file: {}", e);
+            process::exit(1);
+        }
+    };
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let mut filled = module.clone();
+    for (i, answer) in puzzle::example_answers(page).iter().enumerate() {
+        if let Some(answer) = answer {
+            let part = i as u8 + 1;
+            let updated = fill_answer(&filled, part, answer);
+            if updated != filled {
+                println!("Expecting {} for part {} of the example", answer, part);
+                filled = updated;
+            }
+        }
+    }
+
+    if filled != module {
+        if let Err(e) = fs::write(&module_path, filled) {
+            eprintln!("Failed to write module file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn create_variant(year: u16, day: u8, variant: &str) {
+    let example_path = year_example_path(year, day, variant);
+
+    match create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file \"{}\"", example_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn create_day(year: u16, day: u8) {
+    let input_path = year_file_path(year, "inputs", day);
+    let example_path = year_file_path(year, "examples", day);
+    let module_path = layout::bin_path(year, day);
+
+    for path in [&input_path, &example_path, &module_path] {
+        if let Err(e) = fs::create_dir_all(path.parent().unwrap()) {
+            eprintln!("Failed to create folder for \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +269,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module_contents(year, day).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -88,7 +281,7 @@ fn main() {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +291,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -106,9 +299,77 @@ fn main() {
         }
     }
 
+    if year != DEFAULT_YEAR {
+        match register_bin(year, day) {
+            Ok(_) => println!(
+                "Registered bin \"{}\" in Cargo.toml",
+                layout::bin_name(year, day)
+            ),
+            Err(e) => {
+                eprintln!("Failed to register bin in Cargo.toml: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn main() {
+    let Args {
+        day,
+        year,
+        puzzle,
+        variants,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7` or `cargo scaffold 7 --year 2023 --puzzle --variant a --variant b`");
+            process::exit(1);
+        }
+    };
+
+    // with `--puzzle` or `--variant`, an existing day only gets the puzzle and the new examples.
+    if (puzzle || !variants.is_empty()) && layout::bin_path(year, day).exists() {
+        println!("Module file already exists, leaving it as is");
+    } else {
+        create_day(year, day);
+    }
+
+    for variant in variants.iter() {
+        create_variant(year, day, variant);
+    }
+
+    if puzzle {
+        let page = fetch_puzzle(year, day);
+        write_puzzle(year, day, &page);
+        write_example(year, day, &page);
+        write_answers(year, day, &page);
+    }
+
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        layout::bin_name(year, day)
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_answer() {
+        let module = module_contents(DEFAULT_YEAR, 1);
+
+        let filled = fill_answer(&module, 1, "24000");
+        assert!(filled.contains("assert_eq!(part_one(&input), Some(24000));"));
+        assert!(filled.contains("assert_eq!(part_two(&input), None);"));
+
+        let filled = fill_answer(&filled, 2, "MCD");
+        assert!(filled.contains("assert_eq!(part_two(&input), Some(String::from(\"MCD\")));"));
+        assert!(filled.contains("pub fn part_two(input: &str) -> Option<String> {"));
+        assert!(filled.contains("pub fn part_one(input: &str) -> Option<u32> {"));
+
+        // filled in assertions are left alone on a re-run.
+        assert_eq!(fill_answer(&filled, 1, "1"), filled);
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{Client, Submission};
+use advent_of_code::input::InputSource;
+use advent_of_code::layout::DEFAULT_YEAR;
+use advent_of_code::{days, AocError};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+/// Runs the registered solver of `day` on its input.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let solution =
+        days::get(year, day).ok_or_else(|| format!("no solution registered for day {}", day))?;
+    let input = InputSource::resolve(year, day)
+        .read()
+        .map_err(|e| e.to_string())?;
+
+    let answer: Result<Option<String>, AocError> = match part {
+        1 => solution.part_one(&input),
+        _ => solution.part_two(&input),
+    };
+    match answer {
+        Ok(Some(answer)) => Ok(answer),
+        Ok(None) => Err(format!("day {} part {} is not solved yet", day, part)),
+        Err(e) => Err(e.with_day(day).to_string()),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day and part. example: `cargo submit 7 1` or `cargo submit 7 1 1234`");
+            process::exit(1);
+        }
+    };
+
+    if args.part != 1 && args.part != 2 {
+        eprintln!("Part must be 1 or 2, got {}", args.part);
+        process::exit(1);
+    }
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match solve(args.year, args.day, args.part) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("Failed to solve: {}", e);
+                process::exit(1);
+            }
+        },
+    };
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Submitting \"{}\" for {} day {} part {}",
+        answer, args.year, args.day, args.part
+    );
+
+    match client.submit(args.year, args.day, args.part, &answer) {
+        Ok(Submission::Correct) => {
+            println!("---");
+            println!("🎄 That's the right answer! ⭐");
+        }
+        Ok(submission) => {
+            eprintln!("Answer was not accepted: {}", submission);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::input::year_file_path;
+use advent_of_code::layout::{self, DEFAULT_YEAR};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{env, fs};
+
+/// Changes arriving within this window trigger a single run, e.g. an editor writing a file in steps.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+    })
+}
+
+/// The day's module, examples (`NN.txt` and `NN-<variant>.txt`) and input, plain or vaulted.
+fn is_watched(year: u16, day: u8, path: &Path) -> bool {
+    let name = match path.file_name().and_then(|x| x.to_str()) {
+        Some(name) => name,
+        None => return false,
+    };
+    let prefix = format!("{:02}", day);
+
+    match path.parent() {
+        Some(dir) if dir == layout::bin_path(year, day).parent().unwrap() => {
+            name == format!("{}.rs", prefix)
+        }
+        Some(dir) if dir == year_file_path(year, "examples", day).parent().unwrap() => {
+            name == format!("{}.txt", prefix)
+                || (name.starts_with(&format!("{}-", prefix)) && name.ends_with(".txt"))
+        }
+        Some(dir) if dir == year_file_path(year, "inputs", day).parent().unwrap() => {
+            name == format!("{}.txt", prefix) || name == format!("{}.txt.vault", prefix)
+        }
+        _ => false,
+    }
+}
+
+fn cargo() -> Command {
+    Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+}
+
+/// Reads `"key":value` from one of the JSON records printed with `--format json`.
+fn json_value<'a>(record: &'a str, key: &str) -> Option<&'a str> {
+    let start = record.find(&format!("\"{}\":", key))? + key.len() + 3;
+    let value = &record[start..];
+
+    if value.starts_with('"') {
+        let mut escaped = false;
+        for (idx, c) in value.char_indices().skip(1) {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => return Some(&value[..=idx]),
+                _ => {}
+            }
+        }
+        None
+    } else {
+        value.split([',', '}']).next()
+    }
+}
+
+fn json_unescape(value: &str) -> String {
+    let mut unescaped = String::new();
+    let value = value.strip_prefix('"').unwrap_or(value);
+    let mut chars = value.strip_suffix('"').unwrap_or(value).chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                if let Some(c) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    unescaped.push(c);
+                }
+            }
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+/// Answers by part from the JSON output of a solve. Parts without an answer show their status.
+fn parse_answers(output: &str) -> BTreeMap<u8, String> {
+    output
+        .lines()
+        .filter_map(|record| {
+            let part: u8 = json_value(record, "part")?.parse().ok()?;
+            if part == 0 {
+                return None; // the parse step of split solvers has no answer.
+            }
+            let answer = match json_value(record, "answer")? {
+                "null" => format!("<{}>", json_unescape(json_value(record, "status")?)),
+                answer => json_unescape(answer),
+            };
+            Some((part, answer))
+        })
+        .collect()
+}
+
+/// What changed since the previous run, as lines to print.
+fn diff(previous: Option<&BTreeMap<u8, String>>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    let mut lines = vec![];
+
+    for (part, answer) in current {
+        match previous.and_then(|x| x.get(part)) {
+            None => lines.push(format!("Part {}: {}", part, answer)),
+            Some(before) if before == answer => {
+                lines.push(format!("Part {}: {} (unchanged)", part, answer))
+            }
+            Some(before) if !before.contains('\n') && !answer.contains('\n') => {
+                lines.push(format!("Part {}: {} (was {})", part, answer, before))
+            }
+            Some(before) => {
+                lines.push(format!("Part {} changed:", part));
+                lines.extend(before.lines().map(|x| format!("- {}", x)));
+                lines.extend(answer.lines().map(|x| format!("+ {}", x)));
+            }
+        }
+    }
+    lines
+}
+
+/// Rebuilds the day, runs its tests and solves it. `None` if it did not build.
+fn run(bin: &str) -> Option<BTreeMap<u8, String>> {
+    println!("{}Testing {}{}", ANSI_BOLD, bin, ANSI_RESET);
+    let tested = cargo().args(["test", "--quiet", "--bin", bin]).status();
+    if let Err(e) = tested {
+        eprintln!("Failed to run cargo test: {}", e);
+        return None;
+    }
+
+    println!("{}Solving {}{}", ANSI_BOLD, bin, ANSI_RESET);
+    let output = cargo()
+        .args(["run", "--quiet", "--bin", bin, "--", "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() || !output.stdout.is_empty() => {
+            Some(parse_answers(&String::from_utf8_lossy(&output.stdout)))
+        }
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("Failed to run cargo run: {}", e);
+            None
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let bin = layout::bin_name(args.year, args.day);
+    let bin_path = layout::bin_path(args.year, args.day);
+    if !bin_path.exists() {
+        eprintln!(
+            "{} does not exist, create it with `cargo scaffold {}` first.",
+            bin_path.display(),
+            args.day
+        );
+        process::exit(1);
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("Failed to watch files: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // watch the folders rather than the files: editors often replace a file instead of writing to it.
+    let folders: Vec<PathBuf> = [
+        bin_path.clone(),
+        year_file_path(args.year, "examples", args.day),
+        year_file_path(args.year, "inputs", args.day),
+    ]
+    .into_iter()
+    .map(|x| x.parent().unwrap().to_owned())
+    .collect();
+
+    for folder in &folders {
+        if let Err(e) = fs::create_dir_all(folder)
+            .map_err(notify::Error::io)
+            .and_then(|_| watcher.watch(folder, RecursiveMode::NonRecursive))
+        {
+            eprintln!("Failed to watch {}: {}", folder.display(), e);
+            process::exit(1);
+        }
+    }
+
+    let mut previous: Option<BTreeMap<u8, String>> = None;
+    loop {
+        if let Some(answers) = run(&bin) {
+            for line in diff(previous.as_ref(), &answers) {
+                println!("{}", line);
+            }
+            previous = Some(answers);
+        }
+        println!(
+            "Watching day {} for changes. Press Ctrl-C to stop.",
+            args.day
+        );
+
+        // block until a watched file changes, then wait for the changes to settle.
+        let is_change = |event: &notify::Result<notify::Event>| match event {
+            Ok(event) => {
+                matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) && event
+                    .paths
+                    .iter()
+                    .any(|x| is_watched(args.year, args.day, x))
+            }
+            Err(_) => false,
+        };
+        loop {
+            match receiver.recv() {
+                Ok(event) if is_change(&event) => break,
+                Ok(_) => {}
+                Err(_) => process::exit(1),
+            }
+        }
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_answers() {
+        let output = r##"{"year":2022,"day":10,"part":0,"answer":null,"elapsed_ns":1,"status":"solved","verdict":"unknown","contended":false}
+{"year":2022,"day":10,"part":1,"answer":"13140","elapsed_ns":1,"status":"solved","verdict":"unknown","contended":false}
+{"year":2022,"day":10,"part":2,"answer":"#.\n\"x\"","elapsed_ns":1,"status":"solved","verdict":"unknown","contended":false}"##;
+        let answers = parse_answers(output);
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[&1], "13140");
+        assert_eq!(answers[&2], "#.\n\"x\"");
+
+        assert_eq!(diff(None, &answers)[0], "Part 1: 13140");
+
+        let current = BTreeMap::from([(1, String::from("13141")), (2, String::from("#.\n.#"))]);
+        assert_eq!(
+            diff(Some(&answers), &current),
+            [
+                "Part 1: 13141 (was 13140)",
+                "Part 2 changed:",
+                "- #.",
+                "- \"x\"",
+                "+ #.",
+                "+ .#"
+            ]
+        );
+
+        let failed = parse_answers(r#"{"part":1,"answer":null,"status":"panicked"}"#);
+        assert_eq!(failed[&1], "<panicked>");
+    }
+}
@@ -0,0 +1,449 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_root;
+use crate::puzzle::strip_tags;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Points the client at another server, e.g. a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// The session cookie of a logged in browser.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// A file containing the session cookie, `~/.adventofcode.session` by default (shared with aoc-cli).
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+/// The site asks automated tools to say who they are and where to find them.
+pub const USER_AGENT: &str = concat!(
+    "github.com/BKreisel/aoc2022 (advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor a session file is set.
+    NoSession,
+    Status {
+        method: &'static str,
+        url: String,
+        status: u16,
+    },
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session token found. Set {} or put it in ~/{}",
+                SESSION_ENV, SESSION_FILE
+            ),
+            ClientError::Status {
+                method,
+                url,
+                status,
+            } => {
+                let hint = match status {
+                    400 | 401 | 403 => " (is your session token valid and not expired?)",
+                    404 => " (is the puzzle unlocked yet?)",
+                    500..=599 => " (the server had a problem, try again later)",
+                    _ => "",
+                };
+                write!(f, "{} {} returned HTTP {}{}", method, url, status, hint)
+            }
+            ClientError::Transport { url, message } => {
+                write!(f, "could not reach {}: {}", url, message)
+            }
+            ClientError::Io(e) => write!(f, "could not access the cache: {}", e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Reads the session token from `AOC_SESSION`, or else from the session file.
+pub fn session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_owned());
+        }
+    }
+
+    let path = match env::var_os(SESSION_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => match env::var_os("HOME") {
+            Some(home) => Path::new(&home).join(SESSION_FILE),
+            None => return Err(ClientError::NoSession),
+        },
+    };
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+        _ => Err(ClientError::NoSession),
+    }
+}
+
+/// How the site replied to a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answers are rate-limited; the site says how long to wait before the next attempt.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or part one is not solved yet.
+    WrongLevel,
+    /// A reply none of the above matched, with its text.
+    Unknown(String),
+}
+
+impl Submission {
+    /// Classifies the `<article>` of the page returned after POSTing an answer.
+    pub fn from_html(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Submission::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Submission::RateLimited(parse_wait(&text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else if text.contains("your answer is too high") {
+            Submission::TooHigh
+        } else if text.contains("your answer is too low") {
+            Submission::TooLow
+        } else if text.contains("That's not the right answer") {
+            Submission::Wrong
+        } else {
+            Submission::Unknown(text)
+        }
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "correct"),
+            Submission::TooHigh => write!(f, "wrong, too high"),
+            Submission::TooLow => write!(f, "wrong, too low"),
+            Submission::Wrong => write!(f, "wrong"),
+            Submission::RateLimited(Some(wait)) => {
+                write!(f, "rate-limited, wait {}s", wait.as_secs())
+            }
+            Submission::RateLimited(None) => write!(f, "rate-limited"),
+            Submission::WrongLevel => write!(f, "already solved or not unlocked"),
+            Submission::Unknown(text) => write!(f, "unrecognized reply: {}", text),
+        }
+    }
+}
+
+/// The text inside `<article>`, tags stripped, or the whole page without one.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let text = strip_tags(article);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads `You have 1m 5s left to wait` into a duration.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |acc, amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            let seconds = match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+            Some(acc + Duration::from_secs(seconds))
+        })
+}
+
+/// A minimal client for adventofcode.com.
+pub struct Client {
+    base_url: String,
+    session: String,
+    /// Where responses that never change, like inputs, are kept.
+    cache_dir: Option<PathBuf>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            cache_dir: None,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client for `AOC_BASE_URL` (or the real site) caching under `target/aoc-cache`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| BASE_URL.to_owned());
+        let client = Client::new(&base_url, &session()?)
+            .with_cache(crate_root().join("target").join("aoc-cache"));
+        Ok(client)
+    }
+
+    pub fn with_cache(self, cache_dir: impl Into<PathBuf>) -> Self {
+        Client {
+            cache_dir: Some(cache_dir.into()),
+            ..self
+        }
+    }
+
+    /// Fetches `/{year}/day/{day}/input`. Inputs never change, so they are served from the cache once fetched.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get_cached(&format!("/{}/day/{}/input", year, day))
+    }
+
+    fn get_cached(&self, path: &str) -> Result<String, ClientError> {
+        let cache_path = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| dir.join(path.trim_start_matches('/')));
+
+        if let Some(body) = cache_path.as_ref().and_then(|x| fs::read_to_string(x).ok()) {
+            return Ok(body);
+        }
+
+        let body = self.get(path)?;
+
+        if let Some(cache_path) = cache_path {
+            fs::create_dir_all(cache_path.parent().unwrap())?;
+            fs::write(cache_path, &body)?;
+        }
+        Ok(body)
+    }
+
+    /// Fetches the puzzle page `/{year}/day/{day}`. Not cached, as part two appears once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// POSTs `answer` for `part` to `/{year}/day/{day}/answer` and classifies the reply.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response("POST", url, response)?;
+        Ok(Submission::from_html(&html))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response("GET", url, response)
+    }
+}
+
+fn read_response(
+    method: &'static str,
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(|e| ClientError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status {
+            method,
+            url,
+            status,
+        }),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport {
+            url,
+            message: match e.source() {
+                Some(source) => format!("{}: {}", e.kind(), source),
+                None => e.kind().to_string(),
+            },
+        }),
+    }
+}
+
+/// A local stand-in for adventofcode.com with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// A request as seen by the mock server.
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Serves `responses` in order, then stops. Returns the base URL and the requests it received.
+    pub fn serve(responses: Vec<(u16, &str)>) -> (String, Receiver<Request>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_owned()))
+            .collect();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let mut request = match server.recv() {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                let mut request_body = String::new();
+                request
+                    .as_reader()
+                    .read_to_string(&mut request_body)
+                    .unwrap();
+                // the test may not care about the requests and have dropped the receiver.
+                let _ = sender.send(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_owned(),
+                    headers: request
+                        .headers()
+                        .iter()
+                        .map(|x| (x.field.to_string(), x.value.to_string()))
+                        .collect(),
+                    body: request_body,
+                });
+                request
+                    .respond(tiny_http::Response::from_string(body).with_status_code(status))
+                    .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (base_url, requests) = mock::serve(vec![(200, "1000\n2000\n")]);
+        let cache_dir = env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let client = Client::new(&base_url, "abc").with_cache(&cache_dir);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url, "/2022/day/1/input");
+        assert_eq!(request.header("Cookie"), Some("session=abc"));
+        assert_eq!(request.header("User-Agent"), Some(USER_AGENT));
+
+        // served from the cache: the mock would no longer answer.
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_http_error() {
+        let (base_url, _requests) = mock::serve(vec![(400, "Please log in."), (404, "")]);
+        let client = Client::new(&base_url, "expired");
+
+        let error = client.input(2022, 1).unwrap_err();
+        assert!(matches!(error, ClientError::Status { status: 400, .. }));
+        assert!(error.to_string().contains("session token"));
+
+        let error = client.input(2022, 25).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "GET {}/2022/day/25/input returned HTTP 404 (is the puzzle unlocked yet?)",
+                base_url
+            )
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = mock::serve(vec![
+            (200, "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>"),
+            (200, "<article><p>That's not the right answer; your answer is too low.  If you're stuck, <a href=\"/2022/day/1\">re-read</a> the puzzle.</p></article>"),
+            (200, "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>"),
+        ]);
+        let client = Client::new(&base_url, "abc");
+
+        assert_eq!(
+            client.submit(2022, 1, 2, "45000").unwrap(),
+            Submission::Correct
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/2022/day/1/answer");
+        assert_eq!(request.body, "level=2&answer=45000");
+
+        assert_eq!(client.submit(2022, 1, 1, "1").unwrap(), Submission::TooLow);
+        assert_eq!(
+            client.submit(2022, 1, 1, "2").unwrap(),
+            Submission::RateLimited(Some(Duration::from_secs(65)))
+        );
+
+        assert_eq!(
+            Submission::from_html("<article><p>That's not the right answer.</p></article>"),
+            Submission::Wrong
+        );
+        assert_eq!(
+            Submission::from_html(
+                "<article><p>That's not the right answer; your answer is too high.</p></article>"
+            ),
+            Submission::TooHigh
+        );
+    }
+}
@@ -0,0 +1,19 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Every `src/bin/NN.rs` and `src/<year>/bin/NN.rs` is compiled into this module by `build.rs`, so days can be run in-process.
+ */
+use crate::Solution;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub fn all() -> &'static [&'static dyn Solution] {
+    SOLUTIONS
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|x| x.year() == year && x.day() == day)
+        .copied()
+}
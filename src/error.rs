@@ -0,0 +1,83 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// A solver failure, e.g. malformed input. Print it instead of panicking.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AocError {
+    /// Filled in by the runner when the solver leaves it unset.
+    pub day: Option<u8>,
+    /// 1-based line of the input the error was found on.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            day: None,
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(line: usize, message: impl Into<String>) -> Self {
+        AocError {
+            line: Some(line),
+            ..AocError::new(message)
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        AocError {
+            day: self.day.or(Some(day)),
+            ..self
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.line) {
+            (Some(day), Some(line)) => write!(f, "day {}, line {}: {}", day, line, self.message),
+            (Some(day), None) => write!(f, "day {}: {}", day, self.message),
+            (None, Some(line)) => write!(f, "line {}: {}", line, self.message),
+            (None, None) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+/// Parses every line of `input` with `parse`, tagging the first failure with its line number.
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Vec<T>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| AocError::at_line(idx + 1, e)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.parse::<u32>().map_err(|e| e.to_string());
+
+        assert_eq!(parse_lines("1\n2\n3", parse), Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\nx\n3", parse).unwrap_err().with_day(4);
+        assert_eq!(
+            error.to_string(),
+            "day 4, line 2: invalid digit found in string"
+        );
+    }
+}
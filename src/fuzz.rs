@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Property tests for day parsers: well-formed inputs come from `generate`, malformed ones are mutations of them.
+ */
+use crate::generate::{self, Rng};
+use std::env;
+use std::fmt::Display;
+use std::panic;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Sets how many seeds each property tries, e.g. `AOC_FUZZ_CASES=100000 cargo test` for a long run.
+pub const CASES_ENV: &str = "AOC_FUZZ_CASES";
+/// A parser taking longer than this on a small input is considered stuck in a loop.
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn cases() -> u64 {
+    env::var(CASES_ENV)
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(200)
+}
+
+/// A small well-formed input for `day`, about a tenth of a real one.
+pub fn well_formed(day: u8, seed: u64) -> String {
+    generate::generate(2022, day, 0.1, seed).expect("every day with a parser has a generator")
+}
+
+/// Characters the mutations insert: the syntax of most inputs, and some that none expect.
+const NOISE: &str = "09-aZ ,:;=[]>\n\té";
+/// Numbers too large for the integer types parsers use.
+const LARGE_NUMBERS: &[&str] = &["99999999999", "-2147483649", "4294967296"];
+
+/// Applies a few random edits to `input`: deletions, duplications, swaps, truncation and insertions.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.range(1, 4) {
+        let at = rng.index(chars.len() + 1);
+        match rng.index(6) {
+            0 if at < chars.len() => {
+                let end = (at + rng.range(1, 8) as usize).min(chars.len());
+                chars.drain(at..end);
+            }
+            1 if at < chars.len() => {
+                let end = (at + rng.range(1, 16) as usize).min(chars.len());
+                let copy: Vec<char> = chars[at..end].to_vec();
+                chars.splice(at..at, copy);
+            }
+            2 if chars.len() > 1 => {
+                let (len, other) = (chars.len(), rng.index(chars.len()));
+                chars.swap(at.min(len - 1), other);
+            }
+            3 => chars.truncate(at),
+            4 => {
+                let number = rng.pick(LARGE_NUMBERS);
+                chars.splice(at..at, number.chars());
+            }
+            _ => {
+                let noise: Vec<char> = NOISE.chars().collect();
+                chars.insert(at, *rng.pick(&noise));
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Runs `parse` on its own thread: `Err` if it panicked or did not return within [`TIMEOUT`].
+pub fn guarded<T: Send + 'static>(parse: fn(&str) -> T, input: &str) -> Result<T, String> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(|| parse(&input)).map_err(|e| {
+            e.downcast_ref::<&str>()
+                .map(|x| x.to_string())
+                .or_else(|| e.downcast_ref::<String>().cloned())
+                .unwrap_or_default()
+        });
+        // the receiver is gone if we timed out.
+        sender.send(result).ok();
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+        Ok(Ok(parsed)) => Ok(parsed),
+        Ok(Err(message)) => Err(format!("panicked: {}", message)),
+        Err(_) => Err(format!("did not return within {:?}", TIMEOUT)),
+    }
+}
+
+/// Checks that parsing each of `inputs` and printing the result gives back the same text.
+pub fn assert_round_trip<T: Display, E: Display>(inputs: &[&str], parse: fn(&str) -> Result<T, E>) {
+    for input in inputs {
+        match parse(input) {
+            Ok(parsed) => assert_eq!(&parsed.to_string(), input, "round trip"),
+            Err(e) => panic!("failed to parse well-formed {:?}: {}", input, e),
+        }
+    }
+}
+
+/// Checks that `parse` neither panics nor hangs on mutations of `well_formed(seed)` inputs.
+/// `parse` may reject them; only how it does so is checked.
+pub fn assert_no_panic<T: Send + 'static>(
+    well_formed: impl Fn(u64) -> String,
+    parse: fn(&str) -> T,
+) {
+    for seed in 0..cases() {
+        let mut rng = Rng::new(seed);
+        let input = mutate(&mut rng, &well_formed(seed));
+        if let Err(e) = guarded(parse, &input) {
+            panic!("seed {}: parser {} on {:?}", seed, e, input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded() {
+        assert_eq!(guarded(|x| x.len(), "abc"), Ok(3));
+        assert!(guarded(|x| x.as_bytes()[10], "abc")
+            .unwrap_err()
+            .starts_with("panicked: index out of bounds"));
+
+        let mut rng = Rng::new(1);
+        assert_ne!(mutate(&mut rng, "[1,2,3]"), "[1,2,3]");
+    }
+}
@@ -0,0 +1,539 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Synthetic inputs for `cargo gen`. Add a generator for a new day to `generator`.
+ */
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// A small seeded generator (splitmix64), so a seed yields the same input on every platform and version.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        debug_assert!(low <= high, "empty range {}..={}", low, high);
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+/// Writes an input about `size` times as large as a real one.
+pub type Generator = fn(&mut Rng, f64) -> String;
+
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    let generator: Generator = match (year, day) {
+        (2022, 1) => calories,
+        (2022, 2) => strategy_guide,
+        (2022, 3) => rucksacks,
+        (2022, 4) => section_pairs,
+        (2022, 5) => crate_stacks,
+        (2022, 6) => datastream,
+        (2022, 7) => terminal_output,
+        (2022, 8) => tree_heights,
+        (2022, 9) => rope_moves,
+        (2022, 10) => cpu_program,
+        (2022, 11) => monkeys,
+        (2022, 12) => heightmap,
+        (2022, 13) => packet_pairs,
+        (2022, 14) => rock_paths,
+        (2022, 15) => sensors,
+        (2022, 16) => valves,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// `None` if there is no generator for the day.
+pub fn generate(year: u16, day: u8, size: f64, seed: u64) -> Option<String> {
+    generator(year, day).map(|generator| generator(&mut Rng::new(seed), size))
+}
+
+/// `count` scaled by `size`, at least one.
+fn scaled(count: usize, size: f64) -> usize {
+    ((count as f64 * size).round() as usize).max(1)
+}
+
+/// Like [`scaled`] for each side of a square, so its area grows with `size`.
+fn scaled_side(side: usize, size: f64) -> usize {
+    ((side as f64 * size.sqrt()).round() as usize).max(2)
+}
+
+fn calories(rng: &mut Rng, size: f64) -> String {
+    let elves: Vec<String> = (0..scaled(250, size))
+        .map(|_| {
+            let items = rng.range(1, 15);
+            (0..items)
+                .map(|_| rng.range(1000, 60000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+fn strategy_guide(rng: &mut Rng, size: f64) -> String {
+    (0..scaled(2500, size))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+/// Groups of three, each sharing one badge and each line one item between its halves.
+fn rucksacks(rng: &mut Rng, size: f64) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut output = String::new();
+
+    for _ in 0..scaled(100, size) {
+        rng.shuffle(&mut items);
+        let (badge, shared, rest) = (items[0], &items[1..4], &items[4..]);
+        let pool_len = rest.len() / 3;
+
+        for (idx, &shared) in shared.iter().enumerate() {
+            // every line draws from its own items, split between the two halves.
+            let pool = &rest[idx * pool_len..(idx + 1) * pool_len];
+            let (left_pool, right_pool) = pool.split_at(pool_len / 2);
+            let half = rng.range(4, 16) as usize;
+
+            let mut left = vec![badge, shared];
+            left.extend((2..half).map(|_| *rng.pick(left_pool)));
+            let mut right = vec![shared];
+            right.extend((1..half).map(|_| *rng.pick(right_pool)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+
+            output.extend(left.into_iter().chain(right));
+            output.push('\n');
+        }
+    }
+    output
+}
+
+fn section_pairs(rng: &mut Rng, size: f64) -> String {
+    let mut section = || {
+        let start = rng.range(1, 99);
+        (start, rng.range(start, 99))
+    };
+    (0..scaled(1000, size))
+        .map(|_| {
+            let ((a, b), (c, d)) = (section(), section());
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
+
+/// Moves never take the last crate off a stack, as the answer is every stack's top crate.
+fn crate_stacks(rng: &mut Rng, size: f64) -> String {
+    const STACKS: usize = 9;
+    let mut stacks: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| {
+            (0..rng.range(1, 8))
+                .map(|_| (b'A' + rng.index(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut output = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(name) => format!("[{}]", name),
+                None => String::from("   "),
+            })
+            .collect();
+        output.push_str(row.join(" ").trim_end());
+        output.push('\n');
+    }
+    let numbers: Vec<String> = (1..=STACKS).map(|x| format!(" {} ", x)).collect();
+    output.push_str(&numbers.join(" "));
+    output.push_str("\n\n");
+
+    for _ in 0..scaled(500, size) {
+        let src = loop {
+            let src = rng.index(STACKS);
+            if stacks[src].len() > 1 {
+                break src;
+            }
+        };
+        let dst = (src + 1 + rng.index(STACKS - 1)) % STACKS;
+        let count = rng.range(1, (stacks[src].len() - 1).min(20) as i64) as usize;
+
+        let keep = stacks[src].len() - count;
+        let moved = stacks[src].split_off(keep);
+        stacks[dst].extend(moved);
+        writeln!(output, "move {} from {} to {}", count, src + 1, dst + 1).unwrap();
+    }
+    output
+}
+
+/// Few distinct letters until a window of 14 different ones near the end.
+fn datastream(rng: &mut Rng, size: f64) -> String {
+    let len = scaled(4096, size).max(32);
+    let marker_at = len - len / 10 - 14;
+    let common: Vec<char> = ('a'..='h').collect();
+
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+
+    let mut output: String = (0..marker_at).map(|_| *rng.pick(&common)).collect();
+    output.extend(&marker[..14]);
+    output.extend((marker_at + 14..len).map(|_| *rng.pick(&common)));
+    output + "\n"
+}
+
+fn name(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| (b'a' + rng.index(26) as u8) as char)
+        .collect()
+}
+
+/// A directory tree of about 50M bytes in total, so part two has something to free up.
+fn terminal_output(rng: &mut Rng, size: f64) -> String {
+    struct Dir {
+        dirs: Vec<(String, usize)>,
+        files: Vec<(String, u64)>,
+    }
+
+    let (dir_count, file_count) = (scaled(180, size), scaled(280, size));
+    let mut dirs: Vec<Dir> = vec![Dir {
+        dirs: vec![],
+        files: vec![],
+    }];
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new()];
+    fn unique_name(rng: &mut Rng, names: &mut HashSet<String>, extension: bool) -> String {
+        loop {
+            let len = rng.range(1, 8) as usize;
+            let mut file = name(rng, len);
+            if extension && rng.chance(0.5) {
+                file = format!("{}.{}", file, name(rng, 3));
+            }
+            if names.insert(file.clone()) {
+                return file;
+            }
+        }
+    }
+
+    for idx in 1..dir_count {
+        let parent = rng.index(idx);
+        let name = unique_name(rng, &mut names[parent], false);
+        dirs[parent].dirs.push((name, idx));
+        dirs.push(Dir {
+            dirs: vec![],
+            files: vec![],
+        });
+        names.push(HashSet::new());
+    }
+    let average = 50_000_000 / file_count as u64;
+    for _ in 0..file_count {
+        let dir = rng.index(dir_count);
+        let name = unique_name(rng, &mut names[dir], true);
+        dirs[dir]
+            .files
+            .push((name, rng.range(1, 2 * average as i64) as u64));
+    }
+
+    fn list(dirs: &[Dir], idx: usize, output: &mut String) {
+        output.push_str("$ ls\n");
+        for (name, _) in &dirs[idx].dirs {
+            writeln!(output, "dir {}", name).unwrap();
+        }
+        for (name, size) in &dirs[idx].files {
+            writeln!(output, "{} {}", size, name).unwrap();
+        }
+        for (name, child) in &dirs[idx].dirs {
+            writeln!(output, "$ cd {}", name).unwrap();
+            list(dirs, *child, output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut output = String::from("$ cd /\n");
+    list(&dirs, 0, &mut output);
+    output
+}
+
+fn tree_heights(rng: &mut Rng, size: f64) -> String {
+    let side = scaled_side(99, size);
+    (0..side)
+        .map(|_| {
+            let mut row: String = (0..side)
+                .map(|_| (b'0' + rng.index(10) as u8) as char)
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+fn rope_moves(rng: &mut Rng, size: f64) -> String {
+    (0..scaled(2000, size))
+        .map(|_| format!("{} {}\n", rng.pick(&['R', 'U', 'L', 'D']), rng.range(1, 19)))
+        .collect()
+}
+
+/// Keeps the register on the screen, so the CRT draws something.
+fn cpu_program(rng: &mut Rng, size: f64) -> String {
+    let mut register = 1;
+    (0..scaled(145, size))
+        .map(|_| {
+            if rng.chance(0.3) {
+                return String::from("noop\n");
+            }
+            let target = rng.range(0, 39);
+            let value = (target - register).clamp(-38, 38);
+            register += value;
+            format!("addx {}\n", value)
+        })
+        .collect()
+}
+
+/// Divisors are the first primes, whose product keeps `old * old` in a `u64` in part two.
+fn monkeys(rng: &mut Rng, size: f64) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let count = scaled(8, size).clamp(2, PRIMES.len());
+    let mut divisors = PRIMES[..count].to_vec();
+    rng.shuffle(&mut divisors);
+
+    let blocks: Vec<String> = (0..count)
+        .map(|idx| {
+            let items: Vec<String> = (0..scaled(4, size))
+                .map(|_| rng.range(50, 99).to_string())
+                .collect();
+            let operation = match rng.index(3) {
+                0 if idx == 0 => String::from("old * old"),
+                0 | 1 => format!("old + {}", rng.range(1, 8)),
+                _ => format!("old * {}", rng.range(2, 19)),
+            };
+            let mut targets = (0..count).filter(|x| *x != idx).collect::<Vec<_>>();
+            rng.shuffle(&mut targets);
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                idx,
+                items.join(", "),
+                operation,
+                divisors[idx],
+                targets[0],
+                targets[targets.len() - 1]
+            )
+        })
+        .collect();
+    blocks.join("\n")
+}
+
+/// Rises from `a` to `z` left to right, with pits; the top row and the last column stay climbable.
+fn heightmap(rng: &mut Rng, size: f64) -> String {
+    let (width, height) = (scaled_side(160, size).max(27), scaled_side(41, size));
+    let end_row = rng.index(height);
+    let start_row = rng.index(height);
+
+    let mut output = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let elevation = (x * 26 / width) as u8;
+            let c = if (x, y) == (0, start_row) {
+                'S'
+            } else if (x, y) == (width - 1, end_row) {
+                'E'
+            } else if y != 0 && x != width - 1 && rng.chance(0.15) {
+                (b'a' + rng.index(elevation as usize + 1) as u8) as char
+            } else {
+                (b'a' + elevation) as char
+            };
+            output.push(c);
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Lists nest at most five levels deep.
+fn packet(rng: &mut Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.range(0, 5))
+        .map(|_| {
+            if depth >= 4 || rng.chance(0.5) {
+                rng.range(0, 10).to_string()
+            } else {
+                packet(rng, depth + 1)
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn packet_pairs(rng: &mut Rng, size: f64) -> String {
+    let pairs: Vec<String> = (0..scaled(150, size))
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect();
+    pairs.join("\n")
+}
+
+/// Paths of horizontal and vertical segments below the sand source at `500,0`.
+fn rock_paths(rng: &mut Rng, size: f64) -> String {
+    let spread = (40_f64 * size.sqrt()) as i64;
+    // paths start below the first 13 rows, and step down at most 3 * 8 rows more than `depth`,
+    // which keeps them within the 1000 rows day 14 accepts.
+    let depth = ((170_f64 * size.sqrt()) as i64).clamp(13, 1_000 - 3 * 8);
+
+    (0..scaled(150, size))
+        .map(|_| {
+            let mut point = (rng.range(500 - spread, 500 + spread), rng.range(13, depth));
+            let mut points = vec![format!("{},{}", point.0, point.1)];
+            for idx in 0..rng.range(1, 6) {
+                let step = rng.range(-8, 8);
+                if idx % 2 == 0 {
+                    point.0 = (point.0 + step).max(0);
+                } else {
+                    point.1 = (point.1 + step).max(1);
+                }
+                points.push(format!("{},{}", point.0, point.1));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// Sensors spread over the `0..=4000000` square part two searches.
+fn sensors(rng: &mut Rng, size: f64) -> String {
+    (0..scaled(30, size))
+        .map(|_| {
+            let (x, y) = (rng.range(0, 4_000_000), rng.range(0, 4_000_000));
+            let distance = rng.range(50_000, 1_000_000);
+            let dx = rng.range(-distance, distance);
+            let dy = (distance - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x,
+                y,
+                x + dx,
+                y + dy
+            )
+        })
+        .collect()
+}
+
+/// A connected graph of valves starting at `AA`. Only up to 15 valves have a flow rate,
+/// as the solver explores every order of opening them.
+fn valves(rng: &mut Rng, size: f64) -> String {
+    let count = scaled(60, size).clamp(2, 26 * 26);
+    let mut names: Vec<String> = (0..26 * 26)
+        .map(|x| {
+            format!(
+                "{}{}",
+                (b'A' + (x / 26) as u8) as char,
+                (b'A' + (x % 26) as u8) as char
+            )
+        })
+        .filter(|x| x != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+    names.insert(0, String::from("AA"));
+    names.truncate(count);
+
+    let mut tunnels: Vec<Vec<usize>> = vec![vec![]; count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for idx in 1..count {
+        connect(idx, rng.index(idx));
+    }
+    for _ in 0..count / 3 {
+        connect(rng.index(count), rng.index(count));
+    }
+
+    let mut working: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut working);
+    working.truncate(15);
+
+    (0..count)
+        .map(|idx| {
+            let rate = if working.contains(&idx) {
+                rng.range(3, 25)
+            } else {
+                0
+            };
+            let targets: Vec<&str> = tunnels[idx].iter().map(|x| names[*x].as_str()).collect();
+            let tunnels = if targets.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                names[idx],
+                rate,
+                tunnels,
+                targets.join(", ")
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for day in 1..=16 {
+            let input = generate(2022, day, 1_f64, 7).unwrap();
+            assert!(!input.trim().is_empty(), "day {}", day);
+            assert_eq!(generate(2022, day, 1_f64, 7).unwrap(), input, "day {}", day);
+            assert_ne!(generate(2022, day, 1_f64, 8).unwrap(), input, "day {}", day);
+        }
+
+        let small = generate(2022, 9, 1_f64, 7).unwrap();
+        let large = generate(2022, 9, 10_f64, 7).unwrap();
+        assert_eq!(large.lines().count(), 10 * small.lines().count());
+        assert!(generate(2022, 25, 1_f64, 7).is_none());
+    }
+
+    #[test]
+    fn test_generate_tiny() {
+        for day in 1..=16 {
+            for size in [0.005, 0.0001, f64::MIN_POSITIVE] {
+                let input = generate(2022, day, size, 7).unwrap();
+                assert!(!input.trim().is_empty(), "day {} at size {}", day, size);
+            }
+        }
+    }
+}
@@ -2,3 +2,434 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use crate::AocError;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+use std::str::FromStr;
+
+/// The integer types a [`Point`] can be made of.
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The distance between `self` and `other`, which does not underflow for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        match self > other {
+            true => self - other,
+            false => other - self,
+        }
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($type:ty),*) => {
+        $(
+            impl Coordinate for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$type>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i32, i64, isize, u32, u64, usize);
+
+/// A step to one of the four neighbours of a [`Point`]. `Up` is towards smaller `y`, like a
+/// [`Grid`] drawn from the top.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+        Direction::Down,
+    ];
+}
+
+/// A position on a plane: in a [`Grid`], `x` is the column and `y` the row, both from the top left.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The number of king moves between the points, so 1 for every neighbour including diagonals.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// The neighbour in `direction`, or `None` if it does not fit in `T`.
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (x, y) = (self.x, self.y);
+        match direction {
+            Direction::Up => Some(Point::new(x, y.checked_sub(T::ONE)?)),
+            Direction::Left => Some(Point::new(x.checked_sub(T::ONE)?, y)),
+            Direction::Right => Some(Point::new(x.checked_add(T::ONE)?, y)),
+            Direction::Down => Some(Point::new(x, y.checked_add(T::ONE)?)),
+        }
+    }
+
+    /// The points up, left, right and down of this one.
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        Direction::ALL.into_iter().filter_map(|x| self.step(x))
+    }
+
+    /// Like [`Point::neighbours`], with the diagonals too.
+    pub fn neighbours_diagonal(&self) -> impl Iterator<Item = Self> + '_ {
+        let rows = [Direction::Up, Direction::Down].map(|x| self.step(x));
+        let rows = [rows[0], Some(*self), rows[1]];
+        rows.into_iter().flatten().flat_map(move |row| {
+            let columns = [
+                row.step(Direction::Left),
+                Some(row),
+                row.step(Direction::Right),
+            ];
+            columns.into_iter().flatten().filter(move |x| x != self)
+        })
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Parses `"x,y"`, e.g. `"498,4"`.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let bad_point = || format!("Bad Point: {}", input);
+        let (x, y) = input.split_once(',').ok_or_else(bad_point)?;
+        Ok(Point {
+            x: x.parse().map_err(|_| bad_point())?,
+            y: y.parse().map_err(|_| bad_point())?,
+        })
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    items: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, one row per line. `cell` returns `None` for characters that
+    /// are not part of the puzzle, which is an error like rows of different lengths are.
+    pub fn from_str(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut items = vec![];
+
+        for (idx, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(AocError::at_line(idx + 1, "Ragged Row"));
+            }
+            for c in line.chars() {
+                let item = cell(c)
+                    .ok_or_else(|| AocError::at_line(idx + 1, format!("Unexpected {:?}", c)))?;
+                items.push(item);
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height: items.len() / width,
+                items,
+            }),
+            _ => Err(AocError::new("Empty Grid")),
+        }
+    }
+
+    /// A `width` by `height` grid with `cell(point)` at each point. Panics if either is zero.
+    pub fn from_fn(width: usize, height: usize, cell: impl Fn(Point<usize>) -> T) -> Self {
+        if width == 0 || height == 0 {
+            panic!("{}x{} Grid has no cells", width, height);
+        }
+        let items = (0..width * height)
+            .map(|idx| cell(Point::new(idx % width, idx / width)))
+            .collect();
+        Grid {
+            width,
+            height,
+            items,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: &Point<usize>) -> Option<&T> {
+        match self.contains(point) {
+            true => self.items.get(point.y * self.width + point.x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, point: &Point<usize>) -> Option<&mut T> {
+        match self.contains(point) {
+            true => self.items.get_mut(point.y * self.width + point.x),
+            false => None,
+        }
+    }
+
+    /// Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.items.len()).map(move |idx| Point::new(idx % width, idx / width))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.items.iter())
+    }
+
+    pub fn find(&self, item: &T) -> Option<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, x)| *x == item)
+            .map(|(point, _)| point)
+    }
+
+    pub fn find_all(&self, item: &T) -> Vec<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|(_, x)| *x == item)
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    /// The points up, left, right and down of `point` that are inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        point: &'a Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        point.neighbours().filter(|x| self.contains(x))
+    }
+
+    /// Like [`Grid::neighbours`], with the diagonals too.
+    pub fn neighbours_diagonal<'a>(
+        &'a self,
+        point: &'a Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        point.neighbours_diagonal().filter(|x| self.contains(x))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        if y >= self.height {
+            panic!("row {} OOB for Grid", y);
+        }
+        &self.items[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        if x >= self.width {
+            panic!("column {} OOB for Grid", x);
+        }
+        self.items.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.items.chunks(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |point| {
+            self[&Point::new(point.y, point.x)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |point| {
+            self[&Point::new(point.y, self.height - 1 - point.x)].clone()
+        })
+    }
+}
+
+impl<T> Index<&Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: &Point<usize>) -> &T {
+        match self.get(point) {
+            Some(x) => x,
+            None => panic!("({},{}) OOB for Grid", point.x, point.y),
+        }
+    }
+}
+
+impl<T> IndexMut<&Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: &Point<usize>) -> &mut T {
+        match self.get_mut(point) {
+            Some(x) => x,
+            None => panic!("({},{}) OOB for Grid", point.x, point.y),
+        }
+    }
+}
+
+/// Prints the cells row by row, one line per row, e.g. to look at a `Grid<char>`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for item in row {
+                write!(f, "{}", item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::from_str("abc\ndef", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[&Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate().to_string(), "da\neb\nfc");
+
+        let corner: Vec<Point<usize>> = grid.neighbours(&Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_diagonal(&Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_point() {
+        let point: Point<i32> = "3,-4".parse().unwrap();
+        assert_eq!(point.to_string(), "3,-4");
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -3));
+        assert_eq!(point.manhattan(&Point::default()), 7);
+        assert_eq!(point.chebyshev(&Point::default()), 4);
+        assert_eq!(point.step(Direction::Up), Some(Point::new(3, -5)));
+        assert!("3;4".parse::<Point<i32>>().is_err());
+
+        let origin: Point<usize> = Point::default();
+        assert_eq!(origin.step(Direction::Left), None);
+        assert_eq!(origin.neighbours().count(), 2);
+        assert_eq!(Point::new(1_u32, 1).neighbours_diagonal().count(), 8);
+        assert_eq!(origin.manhattan(&Point::new(2, 5)), 7);
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            Grid::from_str("12\n3", digit).unwrap_err().to_string(),
+            "line 2: Ragged Row"
+        );
+        assert_eq!(
+            Grid::from_str("12\n3x", digit).unwrap_err().to_string(),
+            "line 2: Unexpected 'x'"
+        );
+        assert!(Grid::from_str("", digit).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 OOB for Grid")]
+    fn test_grid_column_oob() {
+        Grid::from_str("abc\ndef", Some).unwrap().column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "0x2 Grid has no cells")]
+    fn test_grid_from_fn_empty() {
+        Grid::from_fn(0, 2, |_| 'a');
+    }
+}
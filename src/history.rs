@@ -0,0 +1,326 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_root;
+use crate::output::json_string;
+use crate::solution::{DayResults, Status, PARSE};
+use crate::{ANSI_BOLD, ANSI_RESET};
+use itertools::Itertools;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Every `cargo all` run appends a line per part: `commit, date, year, day, part, elapsed_ns`, tab separated.
+/// Kept out of `target/` so that `cargo clean` does not wipe it.
+pub fn history_path() -> PathBuf {
+    crate_root().join(".aoc-history.tsv")
+}
+
+/// Selects the most recent run when passed to `--compare`.
+pub const LAST: &str = "last";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub commit: String,
+    pub date: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.commit,
+            self.date,
+            self.year,
+            self.day,
+            self.part,
+            self.elapsed.as_nanos()
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        Some(Entry {
+            commit: fields.next()?.to_owned(),
+            date: fields.next()?.to_owned(),
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            elapsed: Duration::from_nanos(fields.next()?.parse().ok()?),
+        })
+    }
+
+    fn same_run(&self, other: &Entry) -> bool {
+        self.commit == other.commit && self.date == other.date
+    }
+}
+
+/// `git describe --always --dirty`, or `unknown` outside of a git checkout.
+pub fn git_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(crate_root())
+        .output()
+        .ok()
+        .filter(|x| x.status.success())
+        .map(|x| String::from_utf8_lossy(&x.stdout).trim().to_owned())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// `YYYY-MM-DDTHH:MM:SSZ` for seconds since the epoch.
+pub fn format_date(secs: u64) -> String {
+    // civil from days, see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let time = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+pub fn now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+    format_date(secs)
+}
+
+/// The solved parts of a run. Timings taken under contention are not comparable and left out.
+pub fn entries(commit: &str, date: &str, year: u16, days: &[(u8, DayResults)]) -> Vec<Entry> {
+    days.iter()
+        .flat_map(|(_, results)| results.iter())
+        .filter(|x| x.status == Status::Solved && !x.contended)
+        .map(|x| Entry {
+            commit: commit.to_owned(),
+            date: date.to_owned(),
+            year,
+            day: x.day,
+            part: x.part,
+            elapsed: x.elapsed,
+        })
+        .collect()
+}
+
+pub fn load() -> io::Result<Vec<Entry>> {
+    match fs::read_to_string(history_path()) {
+        Ok(contents) => Ok(contents.lines().filter_map(Entry::from_line).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(entries: &[Entry]) -> io::Result<()> {
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for entry in entries {
+        writeln!(file, "{}", entry.to_line())?;
+    }
+    Ok(())
+}
+
+/// The entries of the most recent run of `year` at commit `run`, or of the last run with [`LAST`].
+/// `run` may be a prefix of the commit, as long as no other commit starts with it.
+pub fn baseline(history: &[Entry], year: u16, run: &str) -> Result<Vec<Entry>, String> {
+    let runs = || history.iter().rev().filter(|x| x.year == year);
+    let latest = if run == LAST {
+        runs().next()
+    } else if let Some(exact) = runs().find(|x| x.commit == run) {
+        Some(exact)
+    } else {
+        let commits: Vec<&str> = runs()
+            .map(|x| x.commit.as_str())
+            .filter(|x| x.starts_with(run))
+            .unique()
+            .collect();
+        if commits.len() > 1 {
+            return Err(format!(
+                "\"{}\" matches several commits in the timing history: {}",
+                run,
+                commits.join(", ")
+            ));
+        }
+        runs().find(|x| x.commit.starts_with(run))
+    };
+
+    let latest =
+        latest.ok_or_else(|| format!("No run matching \"{}\" in the timing history.", run))?;
+    Ok(history
+        .iter()
+        .filter(|x| x.year == year && x.same_run(latest))
+        .cloned()
+        .collect())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+    /// Relative change in percent, negative when faster.
+    pub percent: f64,
+}
+
+/// Changes of every part timed in both runs.
+pub fn compare(baseline: &[Entry], current: &[Entry]) -> Vec<Change> {
+    current
+        .iter()
+        .filter_map(|after| {
+            let before = baseline
+                .iter()
+                .find(|x| x.day == after.day && x.part == after.part)?;
+            let percent =
+                (after.elapsed.as_secs_f64() / before.elapsed.as_secs_f64() - 1_f64) * 100_f64;
+            Some(Change {
+                day: after.day,
+                part: after.part,
+                before: before.elapsed,
+                after: after.elapsed,
+                percent: if percent.is_finite() { percent } else { 0_f64 },
+            })
+        })
+        .collect()
+}
+
+/// Prints a line per part, flagging changes of more than `threshold` percent.
+pub fn print_comparison(baseline: &[Entry], changes: &[Change], threshold: f64) {
+    let Some(run) = baseline.first() else {
+        return;
+    };
+    println!(
+        "{}Compared to {} ({}):{}",
+        ANSI_BOLD, run.commit, run.date, ANSI_RESET
+    );
+
+    for change in changes {
+        let flag = if change.percent > threshold {
+            "⚠️ regression"
+        } else if change.percent < -threshold {
+            "🚀 faster"
+        } else {
+            ""
+        };
+        let step = if change.part == PARSE {
+            String::from("parse ")
+        } else {
+            format!("part {}", change.part)
+        };
+        println!(
+            "Day {:02} {}: {:.2?} -> {:.2?} ({:+.1}%) {}",
+            change.day, step, change.before, change.after, change.percent, flag
+        );
+    }
+
+    let regressions = changes.iter().filter(|x| x.percent > threshold).count();
+    println!(
+        "{} regression(s) above {}% of {} part(s) compared.",
+        regressions,
+        threshold,
+        changes.len()
+    );
+}
+
+/// A JSON line per part for `--format json`, next to the lines of the parts themselves.
+pub fn change_to_json(run: &Entry, change: &Change, threshold: f64) -> String {
+    format!(
+        "{{\"compare\":{},\"compare_date\":{},\"year\":{},\"day\":{},\"part\":{},\"before_ns\":{},\"after_ns\":{},\"percent\":{:.1},\"regression\":{}}}",
+        json_string(&run.commit),
+        json_string(&run.date),
+        run.year,
+        change.day,
+        change.part,
+        change.before.as_nanos(),
+        change.after.as_nanos(),
+        change.percent,
+        change.percent > threshold
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, date: &str, day: u8, micros: u64) -> Entry {
+        Entry {
+            commit: commit.to_owned(),
+            date: date.to_owned(),
+            year: 2022,
+            day,
+            part: 1,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date(1_670_000_000), "2022-12-02T16:53:20Z");
+        assert_eq!(format_date(951_782_400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = [
+            entry("abc1234", "2022-12-01T00:00:00Z", 12, 400),
+            entry("abc1234", "2022-12-01T00:00:00Z", 13, 100),
+            entry("def5678", "2022-12-02T00:00:00Z", 12, 300),
+        ];
+        assert_eq!(
+            Entry::from_line(&history[0].to_line()),
+            Some(history[0].clone())
+        );
+
+        assert_eq!(baseline(&history, 2022, LAST), Ok(vec![history[2].clone()]));
+        assert_eq!(baseline(&history, 2022, "abc"), Ok(history[..2].to_vec()));
+        assert_eq!(
+            baseline(&history, 2022, "abc1234"),
+            Ok(history[..2].to_vec())
+        );
+        assert!(baseline(&history, 2022, "fff").is_err());
+        assert!(baseline(
+            &[
+                history[0].clone(),
+                entry("abc9999", "2022-12-02T00:00:00Z", 12, 300)
+            ],
+            2022,
+            "abc"
+        )
+        .is_err());
+        assert!(baseline(&history, 2023, LAST).is_err());
+
+        let current = [entry("0000000", "2022-12-03T00:00:00Z", 12, 200)];
+        let changes = compare(&history[..2], &current);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].before, Duration::from_micros(400));
+        assert!((changes[0].percent + 50_f64).abs() < 1.0e-9);
+        assert_eq!(
+            change_to_json(&history[0], &changes[0], 10_f64),
+            "{\"compare\":\"abc1234\",\"compare_date\":\"2022-12-01T00:00:00Z\",\"year\":2022,\"day\":12,\"part\":1,\"before_ns\":400000,\"after_ns\":200000,\"percent\":-50.0,\"regression\":false}"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::layout::{self, DEFAULT_YEAR};
+use crate::options;
+use crate::vault;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+/// Environment variable that overrides where a day reads its input from. `-` reads stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    fn from_arg(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            x => InputSource::Path(PathBuf::from(x)),
+        }
+    }
+
+    /// `--input` wins over `AOC_INPUT`, which wins over the year's `inputs/NN.txt`.
+    pub fn resolve(year: u16, day: u8) -> Self {
+        if let Some(input) = &options::get().input {
+            return InputSource::from_arg(input);
+        }
+        match env::var(INPUT_ENV) {
+            Ok(value) if !value.is_empty() => InputSource::from_arg(&value),
+            _ => InputSource::Path(year_file_path(year, "inputs", day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Path(path) => match fs::read_to_string(path) {
+                // fall back to an encrypted copy committed in place of the input.
+                Err(e) if e.kind() == io::ErrorKind::NotFound => match vault::read(path) {
+                    Some(vaulted) => {
+                        vaulted.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                    }
+                    None => Err(e),
+                },
+                result => result,
+            },
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The directory holding `Cargo.toml`, so paths work no matter where a binary runs from.
+pub fn crate_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn year_file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    layout::year_dir(year)
+        .join(folder)
+        .join(format!("{:02}.txt", day))
+}
+
+pub fn file_path(folder: &str, day: u8) -> PathBuf {
+    year_file_path(DEFAULT_YEAR, folder, day)
+}
+
+pub fn try_read_year_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+    InputSource::Path(year_file_path(year, folder, day)).read()
+}
+
+pub fn try_read_file(folder: &str, day: u8) -> Result<String, InputError> {
+    try_read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// Reads `<folder>/NN.txt` of `year`. Meant for tests: panics if the file is missing.
+pub fn read_year_file(year: u16, folder: &str, day: u8) -> String {
+    match try_read_year_file(year, folder, day) {
+        Ok(input) => input,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Reads `src/<folder>/NN.txt`. Meant for tests: panics if the file is missing.
+pub fn read_file(folder: &str, day: u8) -> String {
+    read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// `examples/NN-<variant>.txt` of `year`, for days with more than one example.
+pub fn year_example_path(year: u16, day: u8, variant: &str) -> PathBuf {
+    layout::year_dir(year)
+        .join("examples")
+        .join(format!("{:02}-{}.txt", day, variant))
+}
+
+/// Reads the example `variant` of a day of `year`. Meant for tests: panics if the file is missing.
+pub fn read_year_example(year: u16, day: u8, variant: &str) -> String {
+    match InputSource::Path(year_example_path(year, day, variant)).read() {
+        Ok(input) => input,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Reads `src/examples/NN-<variant>.txt`, e.g. `read_example(9, "b")` for `09-b.txt`.
+pub fn read_example(day: u8, variant: &str) -> String {
+    read_year_example(DEFAULT_YEAR, day, variant)
+}
+
+/// Reads a day's puzzle input from wherever [`InputSource::resolve`] points.
+/// Exits with the path it tried if that fails, as bins have no better way to recover.
+pub fn read_year_input(year: u16, day: u8) -> String {
+    match InputSource::resolve(year, day).read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+pub fn read_input(day: u8) -> String {
+    read_year_input(DEFAULT_YEAR, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_file() {
+        assert!(try_read_file("examples", 1).unwrap().starts_with("1000"));
+        assert!(read_example(9, "a").starts_with("R 4"));
+
+        let error = try_read_file("examples", 99).unwrap_err();
+        assert_eq!(
+            error.source,
+            InputSource::Path(crate_root().join("src/examples/99.txt"))
+        );
+        assert!(error.to_string().contains("99.txt"));
+    }
+}
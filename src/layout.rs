@@ -0,0 +1,64 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_root;
+use std::path::PathBuf;
+
+/// The year this repository started with. Its days live directly in `src/bin`, `src/inputs` and `src/examples`.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// `src` for [`DEFAULT_YEAR`], `src/<year>` for every other year.
+pub fn year_dir(year: u16) -> PathBuf {
+    let src = crate_root().join("src");
+    if year == DEFAULT_YEAR {
+        src
+    } else {
+        src.join(year.to_string())
+    }
+}
+
+/// `NN` for [`DEFAULT_YEAR`], `YYYY-NN` for every other year.
+pub fn bin_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}-{:02}", year, day)
+    }
+}
+
+/// Reverse of [`bin_name`].
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((DEFAULT_YEAR, name.parse().ok()?)),
+    }
+}
+
+pub fn bin_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join("bin").join(format!("{:02}.rs", day))
+}
+
+/// The puzzle description fetched by `cargo scaffold --puzzle`.
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_name() {
+        assert_eq!(bin_name(DEFAULT_YEAR, 7), "07");
+        assert_eq!(bin_name(2023, 7), "2023-07");
+
+        assert_eq!(parse_bin_name("07"), Some((DEFAULT_YEAR, 7)));
+        assert_eq!(parse_bin_name("2023-07"), Some((2023, 7)));
+        assert_eq!(parse_bin_name("scaffold"), None);
+
+        assert_eq!(year_dir(2023), crate_root().join("src").join("2023"));
+    }
+}
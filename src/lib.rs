@@ -3,92 +3,184 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-
+// lets the day modules compiled into `days` keep using `advent_of_code::` paths.
+extern crate self as advent_of_code;
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod client;
+// day modules are tested through their own bin targets.
+#[cfg(not(test))]
+pub mod days;
+pub mod error;
+pub mod fuzz;
+pub mod generate;
 pub mod helpers;
+pub mod history;
+pub mod input;
+pub mod layout;
+pub mod options;
+pub mod output;
+pub mod puzzle;
+pub mod readme;
+pub mod solution;
+pub mod vault;
+
+pub use error::AocError;
+pub use input::{
+    file_path, read_example, read_file, read_input, read_year_example, read_year_file,
+    read_year_input,
+};
+pub use solution::Solution;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Solves and prints one part: `solve!(1, part_one, input)`.
+/// Split solvers parse once and time it separately: `solve!(parse, part_one, part_two, input)`.
 #[macro_export]
 macro_rules! solve {
+    ($parse:ident, $part_one:ident, $part_two:ident, $input:expr) => {{
+        let (year, day) = option_env!("CARGO_BIN_NAME")
+            .and_then(advent_of_code::layout::parse_bin_name)
+            .unwrap_or((advent_of_code::layout::DEFAULT_YEAR, 0));
+        advent_of_code::solution::solve_parsed(
+            year,
+            day,
+            $parse,
+            |model| $part_one(model),
+            |model| $part_two(model),
+            $input,
+        );
+
+        if advent_of_code::solution::has_wrong_answer() {
+            std::process::exit(1);
+        }
+    }};
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+        // bins are named after their day, e.g. `07` solves day 7 and `2023-07` day 7 of 2023.
+        let (year, day) = option_env!("CARGO_BIN_NAME")
+            .and_then(advent_of_code::layout::parse_bin_name)
+            .unwrap_or((advent_of_code::layout::DEFAULT_YEAR, 0));
+        advent_of_code::solution::solve_part(year, day, $part, $solver, $input);
+
+        // exit non-zero once both parts ran if either disagreed with `answers.toml`.
+        if $part == 2 && advent_of_code::solution::has_wrong_answer() {
+            std::process::exit(1);
         }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Checks a table of `(variant, solver, expected)` cases against the day's named examples.
+///
+/// ```ignore
+/// advent_of_code::example_tests!(test_examples, [
+///     ("a", part_one, Ok(13)),
+///     ("b", part_two, Ok(36)),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($name:ident, [$(($variant:expr, $solver:ident, $expected:expr)),* $(,)?]) => {
+        #[test]
+        fn $name() {
+            // the day comes from the bin name, as with `solve!`.
+            let (year, day) = option_env!("CARGO_BIN_NAME")
+                .and_then(advent_of_code::layout::parse_bin_name)
+                .unwrap_or((advent_of_code::layout::DEFAULT_YEAR, 0));
+            $(
+                let input = advent_of_code::read_year_example(year, day, $variant);
+                assert_eq!(
+                    $solver(&input),
+                    $expected,
+                    "{} of example {}",
+                    stringify!($solver),
+                    $variant
+                );
+            )*
+        }
+    };
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Registers a day with the runner in `main.rs`. Generates a `Solver` implementing [`Solution`].
+/// The year defaults to [`layout::DEFAULT_YEAR`], other years pass it first: `solution!(2023, 1, part_one, part_two)`.
+/// Split solvers, whose parts take the model `parse` returns as `Result<Model, AocError>`, name it: `solution!(7, parse: parse, part_one, part_two)`.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr, parse: $parse:ident, $part_one:ident, $part_two:ident) => {
+        advent_of_code::solution!(
+            advent_of_code::layout::DEFAULT_YEAR,
+            $day,
+            parse: $parse,
+            $part_one,
+            $part_two
+        );
+    };
+    ($year:expr, $day:expr, parse: $parse:ident, $part_one:ident, $part_two:ident) => {
+        #[allow(dead_code)]
+        pub struct Solver;
+
+        impl advent_of_code::Solution for Solver {
+            fn year(&self) -> u16 {
+                $year
+            }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
+            fn day(&self) -> u8 {
+                $day
+            }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
+            fn part_one(&self, input: &str) -> Result<Option<String>, advent_of_code::AocError> {
+                advent_of_code::solution::Answer::into_answer($part_one(&$parse(input)?))
+            }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+            fn part_two(&self, input: &str) -> Result<Option<String>, advent_of_code::AocError> {
+                advent_of_code::solution::Answer::into_answer($part_two(&$parse(input)?))
             }
-        }
-    })
-}
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
+            fn run(&self, input: &str) -> advent_of_code::solution::DayResults {
+                advent_of_code::solution::evaluate_parsed(
+                    $year,
+                    $day,
+                    $parse,
+                    |model| advent_of_code::solution::Answer::into_answer($part_one(model)),
+                    |model| advent_of_code::solution::Answer::into_answer($part_two(model)),
+                    input,
+                )
+            }
+        }
+    };
+    ($day:expr, $part_one:ident, $part_two:ident) => {
+        advent_of_code::solution!(
+            advent_of_code::layout::DEFAULT_YEAR,
+            $day,
+            $part_one,
+            $part_two
         );
-    }};
+    };
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident) => {
+        #[allow(dead_code)]
+        pub struct Solver;
+
+        impl advent_of_code::Solution for Solver {
+            fn year(&self) -> u16 {
+                $year
+            }
+
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn part_one(&self, input: &str) -> Result<Option<String>, advent_of_code::AocError> {
+                advent_of_code::solution::Answer::into_answer($part_one(input))
+            }
+
+            fn part_two(&self, input: &str) -> Result<Option<String>, advent_of_code::AocError> {
+                advent_of_code::solution::Answer::into_answer($part_two(input))
+            }
+        }
+    };
 }
 
 #[cfg(test)]
@@ -96,30 +188,19 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_run_part() {
+        use solution::Answer;
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+        let result = solution::run_part(2022, 1, 2, |input| Some(input.len()).into_answer(), "abc");
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer, Some(String::from("3")));
+        assert_eq!(result.status, solution::Status::Solved);
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+        let result = solution::run_part(2022, 1, 1, |_| panic!(), "");
+        assert_eq!(result.status, solution::Status::Panicked);
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+        let result = solution::run_part(2022, 1, 1, |_| Err(AocError::at_line(3, "bad")), "");
+        assert_eq!(result.status, solution::Status::Failed);
+        assert_eq!(result.error.unwrap().to_string(), "day 1, line 3: bad");
     }
 }
@@ -2,45 +2,198 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::layout::DEFAULT_YEAR;
+use advent_of_code::output::{self, Format};
+use advent_of_code::solution::{self, DayResults, PartResult};
+use advent_of_code::{days, history, readme, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::process;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+const DAYS: u8 = 25;
+
+struct Args {
+    year: u16,
+    jobs: usize,
+    /// Write the timings into the table in `README.md`.
+    readme: bool,
+    /// A commit, or a prefix unique to one, from the timing history, or `last`, to compare this run against.
+    compare: Option<String>,
+    /// Percent a part may get slower before `--compare` flags it.
+    threshold: f64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+        readme: args.contains("--readme"),
+        compare: args.opt_value_from_str("--compare")?,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+    })
+}
+
+/// Results for both parts, or `None` if the day has no solution or input yet.
+fn run_day(year: u16, day: u8) -> Option<DayResults> {
+    let solution = days::get(year, day)?;
+    let input = advent_of_code::input::try_read_year_file(year, "inputs", day).ok()?;
+    Some(solution::run_day(solution, &input))
+}
+
+/// Runs every day of `year` on `jobs` threads and hands the results to `report` in day order.
+/// With more than one job, timings are marked as measured under contention.
+fn run_days(year: u16, jobs: usize, mut report: impl FnMut(u8, Option<DayResults>)) {
+    if jobs <= 1 {
+        for day in 1..=DAYS {
+            report(day, run_day(year, day));
+        }
+        return;
+    }
+
+    let next_day = AtomicU8::new(1);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || loop {
+                let day = next_day.fetch_add(1, Ordering::Relaxed);
+                if day > DAYS {
+                    break;
+                }
+                let results = run_day(year, day).map(|results| {
+                    results.map(|result| PartResult {
+                        contended: true,
+                        ..result
+                    })
+                });
+                sender.send((day, results)).unwrap();
+            });
+        }
+        drop(sender);
+
+        // days finish out of order; hold on to them until all earlier days were reported.
+        let mut pending = BTreeMap::new();
+        let mut expected = 1;
+        for (day, results) in receiver {
+            pending.insert(day, results);
+            while let Some(results) = pending.remove(&expected) {
+                report(expected, results);
+                expected += 1;
+            }
+        }
+    });
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let format = output::format();
+    let mut total = Duration::ZERO;
+    let mut solved = vec![];
 
+    run_days(args.year, args.jobs, |day, results| {
+        if format == Format::Text {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+        match results {
+            Some(results) => {
+                for result in results.iter() {
+                    output::print_header(result.part);
+                    output::print_result(result);
+                    total += result.elapsed;
+                }
+                solved.push((day, results));
+            }
+            None => match format {
+                Format::Text => println!("Not solved."),
+                Format::Json => {
+                    for part in 1..=2 {
+                        output::print_result(&PartResult::not_solved(args.year, day, part));
+                    }
                 }
-            );
+            },
+        }
+    });
 
-            if is_empty {
-                0_f64
+    if format == Format::Text {
+        println!(
+            "{}Total:{} {}{:.2}ms{}{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            if args.jobs > 1 {
+                " (under contention)"
             } else {
-                advent_of_code::parse_exec_time(&output)
+                ""
+            },
+            ANSI_RESET
+        );
+    }
+
+    if args.readme {
+        match readme::update(args.year, &solved) {
+            Ok(_) => {
+                if format == Format::Text {
+                    println!("Updated the table in {}", readme::README_FILE);
+                }
             }
-        })
-        .sum();
+            Err(e) => {
+                eprintln!("Failed to update {}: {}", readme::README_FILE, e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // compare before saving this run, so `--compare last` means the previous one.
+    let entries = history::entries(&history::git_commit(), &history::now(), args.year, &solved);
+    let history = history::load().unwrap_or_else(|e| {
+        eprintln!("Failed to read the timing history: {}", e);
+        process::exit(1);
+    });
+
+    if let Some(run) = &args.compare {
+        let baseline = history::baseline(&history, args.year, run).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
+        let changes = history::compare(&baseline, &entries);
+        match format {
+            Format::Text => history::print_comparison(&baseline, &changes, args.threshold),
+            Format::Json => {
+                for change in &changes {
+                    println!(
+                        "{}",
+                        history::change_to_json(&baseline[0], change, args.threshold)
+                    );
+                }
+            }
+        }
+    }
+
+    if let Err(e) = history::append(&entries) {
+        eprintln!("Failed to save the timing history: {}", e);
+        process::exit(1);
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if solution::has_wrong_answer() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,60 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchConfig;
+use crate::output::Format;
+use std::process;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Flags shared by `cargo solve` and `cargo all`. `cargo solve` runs cargo on the day's binary, so the
+/// flags go after a `--`: `cargo solve 01 -- --format json`, but `cargo all --format json`.
+#[derive(Debug)]
+pub struct Options {
+    pub format: Format,
+    pub bench: Option<BenchConfig>,
+    /// `--input <path>`, or `-` for stdin.
+    pub input: Option<String>,
+}
+
+impl Options {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let format = args.opt_value_from_str("--format")?.unwrap_or(Format::Text);
+
+        let bench = args.contains("--bench");
+        let runs: Option<u32> = args.opt_value_from_str("--runs")?;
+        let budget: Option<u64> = args.opt_value_from_str("--budget")?;
+
+        let bench = match (bench, runs, budget) {
+            (false, None, None) => None,
+            (_, Some(runs), _) => Some(BenchConfig::Runs(runs.max(1))),
+            (_, None, Some(budget)) => Some(BenchConfig::Budget(Duration::from_millis(budget))),
+            (true, None, None) => Some(BenchConfig::default()),
+        };
+
+        let input = args.opt_value_from_str("--input")?;
+
+        Ok(Options {
+            format,
+            bench,
+            input,
+        })
+    }
+}
+
+/// The options passed to the current process, read once.
+pub fn get() -> &'static Options {
+    static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+    OPTIONS.get_or_init(|| {
+        let mut args = pico_args::Arguments::from_env();
+        match Options::parse(&mut args) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    })
+}
@@ -0,0 +1,210 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::alloc;
+use crate::answers::{self, Verdict};
+use crate::solution::{PartResult, Status, PARSE};
+use crate::{options, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line for every day and part.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            x => Err(format!(
+                "unknown format \"{}\", expected \"text\" or \"json\"",
+                x
+            )),
+        }
+    }
+}
+
+pub fn format() -> Format {
+    options::get().format
+}
+
+pub fn print_header(part: u8) {
+    if format() == Format::Text {
+        if part == PARSE {
+            println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+        } else {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+        }
+    }
+}
+
+pub fn print_result(result: &PartResult) {
+    match format() {
+        Format::Text => print_text(result),
+        Format::Json => println!("{}", to_json(result)),
+    }
+}
+
+fn print_text(result: &PartResult) {
+    let verdict = match result.verdict {
+        Verdict::Correct => String::from("✅"),
+        Verdict::Wrong => format!(
+            "❌ (expected: {})",
+            answers::load()
+                .get(result.year, result.day, result.part)
+                .unwrap_or("?")
+        ),
+        Verdict::Unknown => String::from("❔"),
+    };
+
+    let timing = match &result.stats {
+        Some(stats) => format!(
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}",
+            stats.median, stats.min, stats.mean, stats.stddev, stats.runs
+        ),
+        None => format!("elapsed: {:.2?}", result.elapsed),
+    };
+    let timing = match &result.memory {
+        Some(memory) => format!(
+            "{}, peak: {}, allocations: {}",
+            timing,
+            alloc::format_bytes(memory.peak_bytes),
+            memory.allocations
+        ),
+        None => timing,
+    };
+    let timing = if result.contended {
+        format!("{}, under contention", timing)
+    } else {
+        timing
+    };
+
+    match (&result.answer, result.status) {
+        (Some(answer), _) => {
+            println!(
+                "{} {}({}){} {}",
+                answer, ANSI_ITALIC, timing, ANSI_RESET, verdict
+            );
+        }
+        // only a `parse` step succeeds without an answer.
+        (None, Status::Solved) => {
+            println!("parsed {}({}){}", ANSI_ITALIC, timing, ANSI_RESET);
+        }
+        (None, Status::Panicked) => {
+            println!("panicked. {}", verdict)
+        }
+        (None, Status::Failed) => {
+            let error = result.error.as_ref().map(|e| e.to_string());
+            println!("failed: {} {}", error.unwrap_or_default(), verdict)
+        }
+        (None, _) => {
+            println!("not solved. {}", verdict)
+        }
+    }
+}
+
+pub fn to_json(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => json_string(answer),
+        None => String::from("null"),
+    };
+
+    let error = match &result.error {
+        Some(error) => format!(",\"error\":{}", json_string(&error.to_string())),
+        None => String::new(),
+    };
+
+    let bench = match &result.stats {
+        Some(stats) => format!(
+            ",\"bench\":{{\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => String::new(),
+    };
+
+    let memory = match &result.memory {
+        Some(memory) => format!(
+            ",\"memory\":{{\"peak_bytes\":{},\"allocations\":{}}}",
+            memory.peak_bytes, memory.allocations
+        ),
+        None => String::new(),
+    };
+
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"status\":\"{}\",\"verdict\":\"{}\",\"contended\":{}{}{}{}}}",
+        result.year,
+        result.day,
+        result.part,
+        answer,
+        result.elapsed.as_nanos(),
+        result.status.as_str(),
+        result.verdict.as_str(),
+        result.contended,
+        error,
+        bench,
+        memory
+    )
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_to_json() {
+        let solved = PartResult {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some(String::from("#.\n\"x\"")),
+            elapsed: Duration::from_nanos(1500),
+            status: Status::Solved,
+            error: None,
+            verdict: Verdict::Correct,
+            stats: None,
+            contended: false,
+            memory: Some(alloc::MemoryStats {
+                peak_bytes: 2048,
+                allocations: 3,
+            }),
+        };
+        assert_eq!(
+            to_json(&solved),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n\"x\"","elapsed_ns":1500,"status":"solved","verdict":"correct","contended":false,"memory":{"peak_bytes":2048,"allocations":3}}"##
+        );
+
+        assert_eq!(
+            to_json(&PartResult::not_solved(2023, 3, 1)),
+            r#"{"year":2023,"day":3,"part":1,"answer":null,"elapsed_ns":0,"status":"not_solved","verdict":"unknown","contended":false}"#
+        );
+    }
+}
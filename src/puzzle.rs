@@ -0,0 +1,441 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::BASE_URL;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+/// Every `<article>` of a puzzle page: part one, and part two once unlocked.
+pub fn articles(page: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = page;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + end + "</article>".len();
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+/// The puzzle description of a page as Markdown, or `None` if it has no `<article>`.
+pub fn markdown(page: &str) -> Option<String> {
+    let articles = articles(page);
+    if articles.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = articles.into_iter().map(to_markdown).collect();
+    Some(parts.join("\n"))
+}
+
+/// A `<pre><code>` block and how likely it is to be the puzzle's example input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodeBlock {
+    pub text: String,
+    /// The paragraph before the block talks about an example.
+    pub introduced_as_example: bool,
+}
+
+impl CodeBlock {
+    fn score(&self) -> u8 {
+        let multi_line = self.text.trim_end().lines().count() > 1;
+        2 * self.introduced_as_example as u8 + multi_line as u8
+    }
+}
+
+/// Every `<pre><code>` block of the page's articles, in order.
+pub fn code_blocks(page: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+
+    for article in articles(page) {
+        let mut offset = 0;
+        while let Some(start) = article[offset..].find("<pre><code>") {
+            let start = offset + start;
+            let text_start = start + "<pre><code>".len();
+            let Some(end) = article[text_start..].find("</code></pre>") else {
+                break;
+            };
+            let end = text_start + end;
+
+            let before = &article[..start];
+            let paragraph = &before[before.rfind("<p>").unwrap_or(0)..];
+            blocks.push(CodeBlock {
+                text: decode_entities(&strip_tags(&article[text_start..end])),
+                introduced_as_example: strip_tags(paragraph).to_lowercase().contains("example"),
+            });
+            offset = end;
+        }
+    }
+    blocks
+}
+
+/// The blocks most likely to be example inputs: introduced as an example and spanning several lines.
+/// More than one means the pick is ambiguous.
+pub fn example_candidates(page: &str) -> Vec<String> {
+    let blocks = code_blocks(page);
+    let best = blocks.iter().map(|x| x.score()).max().unwrap_or(0);
+
+    let mut candidates: Vec<String> = vec![];
+    for block in blocks.into_iter().filter(|x| x.score() == best) {
+        if !candidates.contains(&block.text) {
+            candidates.push(block.text);
+        }
+    }
+    candidates
+}
+
+/// Picks one of `candidates`, asking on the terminal if there is more than one.
+/// Without a terminal to ask on, the first candidate wins.
+pub fn choose_example(candidates: &[String]) -> Option<&String> {
+    if candidates.len() <= 1 || !io::stdin().is_terminal() {
+        return candidates.first();
+    }
+
+    println!("Found {} possible examples:", candidates.len());
+    for (i, candidate) in candidates.iter().enumerate() {
+        println!("[{}]", i + 1);
+        for line in candidate.lines().take(5) {
+            println!("    {}", line);
+        }
+        if candidate.lines().count() > 5 {
+            println!("    ...");
+        }
+    }
+
+    loop {
+        print!(
+            "Which one is the example? [1-{}, 0 for none]: ",
+            candidates.len()
+        );
+        io::stdout().flush().ok()?;
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        match line.trim().parse::<usize>() {
+            Ok(0) => return None,
+            Ok(x) if x <= candidates.len() => return candidates.get(x - 1),
+            _ => println!("Please enter a number between 0 and {}.", candidates.len()),
+        }
+    }
+}
+
+/// The example answer of each part: the last `<code><em>` of its article, which is how the page highlights it.
+pub fn example_answers(page: &str) -> Vec<Option<String>> {
+    articles(page)
+        .into_iter()
+        .map(|article| {
+            let start = article.rfind("<code><em>")? + "<code><em>".len();
+            let end = start + article[start..].find("</em></code>")?;
+            Some(decode_entities(&strip_tags(&article[start..end])))
+        })
+        .collect()
+}
+
+/// Writes the example picked from `page` to `path`, unless that file already has content.
+/// Returns whether an example was written.
+pub fn write_example(page: &str, path: &Path) -> io::Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|x| !x.trim().is_empty()) {
+        return Ok(false);
+    }
+    let candidates = example_candidates(page);
+    match choose_example(&candidates) {
+        Some(example) => {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, example)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+struct Frame {
+    tag: String,
+    href: Option<String>,
+    content: String,
+    /// An `<em>` inside `<code>`, rendered around the code span instead.
+    had_em: bool,
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut stack = vec![Frame {
+        tag: String::new(),
+        href: None,
+        content: String::new(),
+        had_em: false,
+    }];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let in_pre = stack.iter().any(|x| x.tag == "pre");
+            let text = decode_entities(&rest[..end]);
+            let top = stack.last_mut().unwrap();
+            // whitespace between block elements is not content.
+            let between_blocks = matches!(top.tag.as_str(), "" | "article" | "ul" | "ol");
+            if in_pre {
+                top.content.push_str(&text);
+            } else if !(between_blocks && text.trim().is_empty()) {
+                top.content.push_str(&collapse_whitespace(&text));
+            }
+            rest = &rest[end..];
+            continue;
+        }
+
+        // an unclosed tag runs to the end of the document.
+        let (tag, after) = rest[1..].split_once('>').unwrap_or((&rest[1..], ""));
+        rest = after;
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            // ignore stray closing tags rather than unwinding the whole document.
+            if stack.len() > 1 && stack.last().unwrap().tag == name {
+                let frame = stack.pop().unwrap();
+                let in_pre = stack.iter().any(|x| x.tag == "pre");
+                let in_code = stack.iter().any(|x| x.tag == "code");
+                if frame.tag == "em" && in_code {
+                    for parent in stack.iter_mut().rev() {
+                        if parent.tag == "code" {
+                            parent.had_em = true;
+                            break;
+                        }
+                    }
+                }
+                let rendered = render(frame, in_pre, in_code);
+                stack.last_mut().unwrap().content.push_str(&rendered);
+            }
+            continue;
+        }
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "br" => stack.last_mut().unwrap().content.push('\n'),
+            "img" | "hr" | "input" | "meta" | "link" | "" => {}
+            _ => stack.push(Frame {
+                href: attribute(tag, "href"),
+                tag: name,
+                content: String::new(),
+                had_em: false,
+            }),
+        }
+    }
+
+    // close whatever the HTML left open.
+    while stack.len() > 1 {
+        let frame = stack.pop().unwrap();
+        let rendered = render(frame, false, false);
+        stack.last_mut().unwrap().content.push_str(&rendered);
+    }
+
+    let markdown = stack.pop().unwrap().content;
+    let mut markdown = markdown.trim().to_owned();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.push('\n');
+    markdown
+}
+
+fn render(frame: Frame, in_pre: bool, in_code: bool) -> String {
+    let content = frame.content;
+    if in_pre {
+        return content;
+    }
+
+    match frame.tag.as_str() {
+        "h1" | "h2" | "h3" => format!("\n\n## {}\n\n", content.trim()),
+        "p" => format!("\n\n{}\n\n", content.trim()),
+        "em" | "strong" | "b" if !in_code => format!("**{}**", content),
+        "code" if frame.had_em => format!("**`{}`**", content),
+        "code" => format!("`{}`", content),
+        "pre" => {
+            let content = content.trim_start_matches('\n');
+            let newline = if content.ends_with('\n') { "" } else { "\n" };
+            format!("\n\n```\n{}{}```\n\n", content, newline)
+        }
+        "ul" | "ol" => format!("\n\n{}\n\n", content.trim_end()),
+        "li" => format!("- {}\n", content.trim()),
+        "a" => match frame.href {
+            Some(href) if href.starts_with('/') => format!("[{}]({}{})", content, BASE_URL, href),
+            Some(href) => format!("[{}]({})", content, href),
+            None => content,
+        },
+        _ => content,
+    }
+}
+
+/// The value of `name="..."` in the inside of a tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !space {
+                collapsed.push(' ');
+            }
+            space = true;
+        } else {
+            collapsed.push(c);
+            space = false;
+        }
+    }
+    collapsed
+}
+
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock, Client};
+
+    const PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="https://en.wikipedia.org/wiki/Calorie">Calories</a> contained by the various meals.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>4000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories.</li>
+<li>Find the Elf carrying the <em>most Calories</em>.</li>
+</ul>
+<p>In the example above, this is <code><em>24000</em></code> (carried by the fourth Elf &amp; friends).</p>
+</article>"#;
+
+    const PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves: <code><em>45000</em></code>.</p></article>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PART_ONE),
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](https://en.wikipedia.org/wiki/Calorie) contained by the various meals.
+
+For example:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories.
+- Find the Elf carrying the **most Calories**.
+
+In the example above, this is **`24000`** (carried by the fourth Elf & friends).
+"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_unclosed_tag() {
+        assert_eq!(to_markdown("<p>café</p><a é"), "café\n");
+        assert_eq!(to_markdown("<p>café</p><é"), "café\n");
+    }
+
+    #[test]
+    fn test_puzzle_from_server() {
+        let page_one = format!("<html><body><main>{}</main></body></html>", PART_ONE);
+        let page_two = format!(
+            "<main>{}<p>Answer: <code>24000</code></p>{}</main>",
+            PART_ONE, PART_TWO
+        );
+        let (base_url, requests) = mock::serve(vec![(200, &page_one), (200, &page_two)]);
+        let client = Client::new(&base_url, "abc");
+
+        let markdown_one = markdown(&client.puzzle(2022, 1).unwrap()).unwrap();
+        assert_eq!(requests.recv().unwrap().url, "/2022/day/1");
+        assert!(!markdown_one.contains("Part Two"));
+
+        let markdown_two = markdown(&client.puzzle(2022, 1).unwrap()).unwrap();
+        assert!(markdown_two.starts_with(&markdown_one));
+        assert!(markdown_two
+            .ends_with("## --- Part Two ---\n\nFind the top **three** Elves: **`45000`**.\n"));
+
+        assert_eq!(markdown("<html>Please log in.</html>"), None);
+        assert_eq!(
+            example_answers(&page_one),
+            vec![Some(String::from("24000"))]
+        );
+        assert_eq!(
+            example_answers(&page_two),
+            vec![Some(String::from("24000")), Some(String::from("45000"))]
+        );
+    }
+
+    #[test]
+    fn test_example_candidates() {
+        let page = format!(
+            "<main>{}</main>",
+            r#"<article><p>Consider a <em>rope</em>:</p>
+<pre><code>....
+.TH.
+....
+</code></pre>
+<p>For example:</p>
+<pre><code>R 4
+U 4
+</code></pre>
+<p>This goes on: <code>H</code> moves.</p>
+<pre><code>== R 4 ==
+</code></pre></article>"#
+        );
+        assert_eq!(example_candidates(&page), vec![String::from("R 4\nU 4\n")]);
+
+        let page_two = format!(
+            "{}<article><p>Here's a larger example:</p>\n<pre><code>R 5\nU 8\n</code></pre></article>",
+            page
+        );
+        assert_eq!(
+            example_candidates(&page_two),
+            vec![String::from("R 4\nU 4\n"), String::from("R 5\nU 8\n")]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Verdict;
+use crate::input::crate_root;
+use crate::layout;
+use crate::solution::{DayResults, PartResult, Status};
+use std::fs;
+use std::io;
+use std::time::Duration;
+
+pub const README_FILE: &str = "README.md";
+/// Everything between two of these is replaced by `cargo all --readme`.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+fn cell(result: &PartResult) -> String {
+    match result.status {
+        Status::Solved => format!("`{:.2?}`", result.elapsed),
+        _ => String::from("-"),
+    }
+}
+
+fn star(result: &PartResult) -> &'static str {
+    match (result.status, result.verdict) {
+        (Status::Solved, Verdict::Wrong) => "❌",
+        (Status::Solved, _) => "⭐",
+        _ => "",
+    }
+}
+
+/// A Markdown table with a row per day that has results, followed by the total time.
+/// The `parse` step of split solvers counts towards the total only.
+pub fn table(year: u16, days: &[(u8, DayResults)]) -> String {
+    let mut table =
+        String::from("| Day | Part 1 | Part 2 | Solved |\n| :---: | :---: | :---: | :---: |\n");
+    let mut total = Duration::ZERO;
+
+    for (day, results) in days {
+        let path = layout::bin_path(year, *day);
+        let path = path.strip_prefix(crate_root()).unwrap_or(&path);
+        table.push_str(&format!(
+            "| [Day {}](./{}) | {} | {} | {}{} |\n",
+            day,
+            path.display(),
+            cell(&results.parts[0]),
+            cell(&results.parts[1]),
+            star(&results.parts[0]),
+            star(&results.parts[1])
+        ));
+        total += results.iter().map(|x| x.elapsed).sum::<Duration>();
+    }
+
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+/// Puts `table` between the markers of `readme`, adding a section at the end if there are none.
+/// A lone marker is an error, as replacing up to the end of the file would lose whatever follows it.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let block = format!("{}\n{}{}", MARKER, table, MARKER);
+
+    match readme.find(MARKER) {
+        Some(start) => {
+            let after = start + MARKER.len();
+            let end = readme[after..]
+                .find(MARKER)
+                .map(|x| after + x + MARKER.len())
+                .ok_or_else(|| format!("found only one {} marker", MARKER))?;
+            Ok(format!("{}{}{}", &readme[..start], block, &readme[end..]))
+        }
+        None => Ok(format!(
+            "{}\n\n## Benchmarks\n\n{}\n",
+            readme.trim_end_matches('\n'),
+            block
+        )),
+    }
+}
+
+/// Writes the timing table into `README.md`, leaving it unchanged if its markers are broken.
+pub fn update(year: u16, days: &[(u8, DayResults)]) -> io::Result<()> {
+    let path = crate_root().join(README_FILE);
+    let readme = fs::read_to_string(&path)?;
+    let updated = replace_table(&readme, &table(year, days))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(day: u8, part: u8, micros: u64) -> PartResult {
+        PartResult {
+            answer: Some(String::from("1")),
+            elapsed: Duration::from_micros(micros),
+            status: Status::Solved,
+            ..PartResult::not_solved(layout::DEFAULT_YEAR, day, part)
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let days = [(
+            1,
+            DayResults {
+                parse: Some(solved(1, 0, 50)),
+                parts: [solved(1, 1, 250), PartResult::not_solved(2022, 1, 2)],
+            },
+        )];
+        assert_eq!(
+            table(2022, &days),
+            "| Day | Part 1 | Part 2 | Solved |
+| :---: | :---: | :---: | :---: |
+| [Day 1](./src/bin/01.rs) | `250.00µs` | - | ⭐ |
+
+**Total: 0.30ms**
+"
+        );
+
+        let readme = format!("# AoC\n{}\nold\n{}\nfooter\n", MARKER, MARKER);
+        assert_eq!(
+            replace_table(&readme, "new\n"),
+            Ok(format!("# AoC\n{}\nnew\n{}\nfooter\n", MARKER, MARKER))
+        );
+        assert_eq!(
+            replace_table("# AoC\n", "new\n"),
+            Ok(format!(
+                "# AoC\n\n## Benchmarks\n\n{}\nnew\n{}\n",
+                MARKER, MARKER
+            ))
+        );
+    }
+
+    #[test]
+    fn test_replace_table_one_marker() {
+        let readme = format!("# AoC\n{}\nold\n## Notes\nkeep me\n", MARKER);
+        assert!(replace_table(&readme, "new\n")
+            .unwrap_err()
+            .contains("only one"));
+    }
+}
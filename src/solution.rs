@@ -0,0 +1,307 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::alloc::{Measurement, MemoryStats};
+use crate::answers::{self, Verdict};
+use crate::bench::{self, Stats};
+use crate::error::AocError;
+use crate::{options, output};
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// A day's solver, as called by the runner in `main.rs`.
+/// Implemented for every `src/bin/NN.rs` and `src/<year>/bin/NN.rs` through the [`solution!`](crate::solution) macro.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part_one(&self, input: &str) -> Result<Option<String>, AocError>;
+    fn part_two(&self, input: &str) -> Result<Option<String>, AocError>;
+
+    /// Runs both parts. Split solvers override this to parse the input only once.
+    fn run(&self, input: &str) -> DayResults {
+        let (year, day) = (self.year(), self.day());
+        DayResults {
+            parse: None,
+            parts: [
+                evaluate_part(year, day, 1, |x| self.part_one(x), input),
+                evaluate_part(year, day, 2, |x| self.part_two(x), input),
+            ],
+        }
+    }
+}
+
+/// What a solver may return: `Option<T>`, or `Result<T, AocError>` when it can fail on bad input.
+pub trait Answer {
+    fn into_answer(self) -> Result<Option<String>, AocError>;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, AocError> {
+        Ok(self.map(|x| x.to_string()))
+    }
+}
+
+impl<T: Display> Answer for Result<T, AocError> {
+    fn into_answer(self) -> Result<Option<String>, AocError> {
+        self.map(|x| Some(x.to_string()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Failed,
+    Panicked,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::NotSolved => "not_solved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// Why the solver returned an error, with [`Status::Failed`].
+    pub error: Option<AocError>,
+    pub verdict: Verdict,
+    /// Set with `--bench`, in which case `elapsed` is the median run.
+    pub stats: Option<Stats>,
+    /// Measured while other days ran in parallel (`cargo all --jobs N`).
+    pub contended: bool,
+    /// Set with the `memory` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartResult {
+    pub fn not_solved(year: u16, day: u8, part: u8) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: Status::NotSolved,
+            error: None,
+            verdict: Verdict::Unknown,
+            stats: None,
+            contended: false,
+            memory: None,
+        }
+    }
+}
+
+/// What split solvers report their `parse` step as, e.g. `"part":0` in JSON output.
+pub const PARSE: u8 = 0;
+
+/// Both parts of a day, after the `parse` step for split solvers.
+#[derive(Clone, Debug)]
+pub struct DayResults {
+    pub parse: Option<PartResult>,
+    pub parts: [PartResult; 2],
+}
+
+impl DayResults {
+    /// Every step in the order it ran.
+    pub fn iter(&self) -> impl Iterator<Item = &PartResult> {
+        self.parse.iter().chain(self.parts.iter())
+    }
+
+    pub fn map(self, f: impl Fn(PartResult) -> PartResult) -> Self {
+        DayResults {
+            parse: self.parse.map(&f),
+            parts: self.parts.map(&f),
+        }
+    }
+}
+
+static WRONG_ANSWER: AtomicBool = AtomicBool::new(false);
+
+/// Runs and times one part. A panicking solver is reported as [`Status::Panicked`] instead of unwinding further.
+pub fn run_part(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&str) -> Result<Option<String>, AocError>,
+    input: &str,
+) -> PartResult {
+    let measurement = Measurement::start();
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+    let elapsed = timer.elapsed();
+    let memory = measurement.finish();
+
+    let (answer, status, error) = match result {
+        Ok(Ok(Some(answer))) => (Some(answer), Status::Solved, None),
+        Ok(Ok(None)) => (None, Status::NotSolved, None),
+        Ok(Err(e)) => (None, Status::Failed, Some(e.with_day(day))),
+        Err(_) => (None, Status::Panicked, None),
+    };
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        error,
+        verdict: Verdict::Unknown,
+        stats: None,
+        contended: false,
+        memory,
+    }
+}
+
+/// Runs one part and checks it against `answers.toml`.
+/// With `--bench`, a solved part is then sampled repeatedly and reports its median time.
+pub fn evaluate_part(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Result<Option<String>, AocError>,
+    input: &str,
+) -> PartResult {
+    let mut result = run_part(year, day, part, &func, input);
+    if let (Some(config), Status::Solved) = (options::get().bench, result.status) {
+        let stats = bench::bench(config, &func, input);
+        result.elapsed = stats.median;
+        result.stats = Some(stats);
+    }
+    result.verdict = answers::load().verdict(year, day, part, result.answer.as_deref());
+    if result.verdict == Verdict::Wrong {
+        WRONG_ANSWER.store(true, Ordering::Relaxed);
+    }
+    result
+}
+
+/// Evaluates one part and prints the result in the format selected with `--format`.
+pub fn solve_part<R: Answer>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> R,
+    input: &str,
+) -> PartResult {
+    output::print_header(part);
+    let result = evaluate_part(year, day, part, |x| func(x).into_answer(), input);
+    output::print_result(&result);
+    result
+}
+
+/// Runs and times the `parse` step of a split solver, returning the model unless it failed or panicked.
+fn parse_step<M>(
+    year: u16,
+    day: u8,
+    parse: impl Fn(&str) -> Result<M, AocError>,
+    input: &str,
+) -> (PartResult, Option<M>) {
+    let mut model = None;
+    let mut result = run_part(
+        year,
+        day,
+        PARSE,
+        |x| {
+            model = Some(parse(x)?);
+            Ok(None)
+        },
+        input,
+    );
+
+    if model.is_some() {
+        result.status = Status::Solved;
+        if let Some(config) = options::get().bench {
+            let stats = bench::bench(config, &parse, input);
+            result.elapsed = stats.median;
+            result.stats = Some(stats);
+        }
+    }
+    (result, model)
+}
+
+/// A part that did not run because parsing failed takes the status and error of the `parse` step.
+fn skipped_part(parsed: &PartResult, part: u8) -> PartResult {
+    PartResult {
+        status: parsed.status,
+        error: parsed.error.clone(),
+        ..PartResult::not_solved(parsed.year, parsed.day, part)
+    }
+}
+
+/// Parses `input` once, then evaluates both parts on the model. Each step is timed on its own.
+pub fn evaluate_parsed<M>(
+    year: u16,
+    day: u8,
+    parse: impl Fn(&str) -> Result<M, AocError>,
+    part_one: impl Fn(&M) -> Result<Option<String>, AocError>,
+    part_two: impl Fn(&M) -> Result<Option<String>, AocError>,
+    input: &str,
+) -> DayResults {
+    let (parsed, model) = parse_step(year, day, parse, input);
+    let parts = match &model {
+        Some(model) => [
+            evaluate_part(year, day, 1, |_| part_one(model), input),
+            evaluate_part(year, day, 2, |_| part_two(model), input),
+        ],
+        None => [skipped_part(&parsed, 1), skipped_part(&parsed, 2)],
+    };
+    DayResults {
+        parse: Some(parsed),
+        parts,
+    }
+}
+
+/// Like [`evaluate_parsed`], printing each step as it finishes.
+pub fn solve_parsed<M, R1: Answer, R2: Answer>(
+    year: u16,
+    day: u8,
+    parse: impl Fn(&str) -> Result<M, AocError>,
+    part_one: impl Fn(&M) -> R1,
+    part_two: impl Fn(&M) -> R2,
+    input: &str,
+) -> DayResults {
+    output::print_header(PARSE);
+    let (parsed, model) = parse_step(year, day, parse, input);
+    output::print_result(&parsed);
+
+    let parts = match &model {
+        Some(model) => [
+            solve_part(year, day, 1, |_| part_one(model), input),
+            solve_part(year, day, 2, |_| part_two(model), input),
+        ],
+        None => [1, 2].map(|part| {
+            let result = skipped_part(&parsed, part);
+            output::print_header(part);
+            output::print_result(&result);
+            result
+        }),
+    };
+    DayResults {
+        parse: Some(parsed),
+        parts,
+    }
+}
+
+/// Whether any part solved so far disagreed with `answers.toml`.
+pub fn has_wrong_answer() -> bool {
+    WRONG_ANSWER.load(Ordering::Relaxed)
+}
+
+pub fn run_day(solution: &dyn Solution, input: &str) -> DayResults {
+    solution.run(input)
+}
@@ -0,0 +1,180 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_root;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::{env, fs};
+
+/// The passphrase the vault is encrypted with.
+pub const KEY_ENV: &str = "AOC_VAULT_KEY";
+/// A file whose contents are used as the passphrase, `.vault-key` in the crate root by default.
+pub const KEY_FILE_ENV: &str = "AOC_VAULT_KEY_FILE";
+pub const KEY_FILE: &str = ".vault-key";
+/// Encrypted inputs sit next to where the plain ones would be: `inputs/NN.txt.vault`.
+pub const EXTENSION: &str = "vault";
+
+const MAGIC: &[u8] = b"AOCVAULT1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum VaultError {
+    /// Neither `AOC_VAULT_KEY` nor a key file is set.
+    NoKey,
+    NotAVault,
+    /// The key is wrong or the blob was modified.
+    Decrypt,
+    KeyDerivation(String),
+}
+
+impl Display for VaultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaultError::NoKey => write!(
+                f,
+                "the input is encrypted, but no key was found. Set {} or put it in {}",
+                KEY_ENV, KEY_FILE
+            ),
+            VaultError::NotAVault => write!(f, "not an encrypted input"),
+            VaultError::Decrypt => write!(f, "could not decrypt input: wrong key?"),
+            VaultError::KeyDerivation(e) => write!(f, "could not derive key: {}", e),
+        }
+    }
+}
+
+impl Error for VaultError {}
+
+/// The passphrase from `AOC_VAULT_KEY`, or else the contents of the key file.
+pub fn secret() -> Option<Vec<u8>> {
+    if let Ok(passphrase) = env::var(KEY_ENV) {
+        if !passphrase.is_empty() {
+            return Some(passphrase.into_bytes());
+        }
+    }
+
+    let path = match env::var_os(KEY_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => crate_root().join(KEY_FILE),
+    };
+    // a trailing newline from `echo passphrase > .vault-key` is not part of the key.
+    let mut secret = fs::read(path).ok()?;
+    while secret.last().is_some_and(|x| x.is_ascii_whitespace()) {
+        secret.pop();
+    }
+    Some(secret).filter(|x| !x.is_empty())
+}
+
+/// `NN.txt` -> `NN.txt.vault`.
+pub fn blob_path(path: &Path) -> PathBuf {
+    let mut blob = path.as_os_str().to_owned();
+    blob.push(".");
+    blob.push(EXTENSION);
+    PathBuf::from(blob)
+}
+
+/// Derived keys by secret and salt.
+type KeyCache = HashMap<(Vec<u8>, Vec<u8>), Key>;
+
+/// Argon2 is slow on purpose; a process reading many inputs derives each key only once.
+fn derive_key(secret: &[u8], salt: &[u8]) -> Result<Key, VaultError> {
+    static KEYS: OnceLock<Mutex<KeyCache>> = OnceLock::new();
+
+    let mut keys = KEYS.get_or_init(Default::default).lock().unwrap();
+    if let Some(key) = keys.get(&(secret.to_vec(), salt.to_vec())) {
+        return Ok(*key);
+    }
+
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(secret, salt, &mut key)
+        .map_err(|e| VaultError::KeyDerivation(e.to_string()))?;
+    keys.insert((secret.to_vec(), salt.to_vec()), key);
+    Ok(key)
+}
+
+/// Encrypts inputs with one salt, so a whole vault costs a single key derivation.
+pub struct Vault {
+    salt: [u8; SALT_LEN],
+    cipher: ChaCha20Poly1305,
+}
+
+impl Vault {
+    pub fn new(secret: &[u8]) -> Result<Self, VaultError> {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let key = derive_key(secret, &salt)?;
+        Ok(Vault {
+            salt,
+            cipher: ChaCha20Poly1305::new(&key),
+        })
+    }
+
+    /// `MAGIC | salt | nonce | ciphertext`.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext)
+            .expect("encrypting in memory does not fail");
+
+        [MAGIC, &self.salt, &nonce, &ciphertext].concat()
+    }
+}
+
+pub fn decrypt(secret: &[u8], blob: &[u8]) -> Result<Vec<u8>, VaultError> {
+    let blob = blob.strip_prefix(MAGIC).ok_or(VaultError::NotAVault)?;
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err(VaultError::NotAVault);
+    }
+    let (salt, blob) = blob.split_at(SALT_LEN);
+    let (nonce, ciphertext) = blob.split_at(NONCE_LEN);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(secret, salt)?);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| VaultError::Decrypt)
+}
+
+/// Reads the vaulted version of `path`, if there is one.
+pub fn read(path: &Path) -> Option<Result<String, VaultError>> {
+    let blob = fs::read(blob_path(path)).ok()?;
+    let result = secret()
+        .ok_or(VaultError::NoKey)
+        .and_then(|secret| decrypt(&secret, &blob))
+        .map(|plaintext| String::from_utf8_lossy(&plaintext).into_owned());
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let vault = Vault::new(b"hunter2").unwrap();
+        let blob = vault.encrypt(b"1000\n2000\n");
+
+        assert!(!blob.windows(4).any(|x| x == b"1000"));
+        assert_eq!(decrypt(b"hunter2", &blob).unwrap(), b"1000\n2000\n");
+        assert_eq!(decrypt(b"hunter3", &blob), Err(VaultError::Decrypt));
+
+        let mut tampered = blob.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert_eq!(decrypt(b"hunter2", &tampered), Err(VaultError::Decrypt));
+        assert_eq!(decrypt(b"hunter2", b"1000"), Err(VaultError::NotAVault));
+
+        assert_eq!(
+            blob_path(Path::new("src/inputs/01.txt")),
+            Path::new("src/inputs/01.txt.vault")
+        );
+    }
+}